@@ -0,0 +1,72 @@
+//! ASN.1 UTCTime and GeneralizedTime, as used by X.509 certificates (RFC 5280).
+
+use crate::{tz_offset, with_local_tz, Error};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+
+/// Try to parse the following types of certificate dates
+/// 230807082350Z (UTCTime)
+/// 20230807082350Z, 20230807082350.123Z, 20230807082350+0200 (GeneralizedTime)
+/// 20230807082350 (local GeneralizedTime)
+/// notAfter=Aug  7 08:23:50 2023 GMT (`openssl x509 -dates`)
+pub(crate) fn from_asn1_time(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s.trim();
+    let s = s
+        .strip_prefix("notBefore=")
+        .or_else(|| s.strip_prefix("notAfter="))
+        .unwrap_or(s);
+    if s.contains(' ') {
+        return NaiveDateTime::parse_from_str(s, "%b %e %T %Y GMT")
+            .map(|x| x.and_utc().fixed_offset())
+            .map_err(|e| e.to_string());
+    }
+    let (digits, offset) = match s.strip_suffix('Z') {
+        Some(digits) => (digits, FixedOffset::east_opt(0)),
+        None if s.len() > 5 && s.is_char_boundary(s.len() - 5) => {
+            let (digits, tz) = s.split_at(s.len() - 5);
+            match tz_offset(tz).filter(|_| tz.starts_with(['+', '-'])) {
+                Some(offset) => (digits, Some(offset)),
+                None => (s, None),
+            }
+        }
+        None => (s, None),
+    };
+    let (digits, fraction) = digits.split_once(['.', ',']).unwrap_or((digits, ""));
+    if !digits.chars().all(|c| c.is_ascii_digit()) || !fraction.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err("custom parsing failed".to_string());
+    }
+    let (year, rest) = match digits.len() {
+        // UTCTime, YYMMDDHHMM[SS] with the RFC 5280 window of 1950 to 2049
+        10 | 12 if fraction.is_empty() && offset.is_some() => {
+            let yy = digits[..2].parse::<i32>().map_err(|e| e.to_string())?;
+            (if yy >= 50 { 1900 + yy } else { 2000 + yy }, &digits[2..])
+        }
+        // GeneralizedTime, YYYYMMDDHHMMSS[.fff], in local time without a zone
+        14 => (
+            digits[..4].parse::<i32>().map_err(|e| e.to_string())?,
+            &digits[4..],
+        ),
+        _ => return Err("custom parsing failed".to_string()),
+    };
+    let field = |i: usize| rest.get(i..i + 2).and_then(|x| x.parse::<u32>().ok());
+    let nanos = format!("{:0<9}", fraction)[..9]
+        .parse::<u32>()
+        .map_err(|e| e.to_string())?;
+    let dt = NaiveDate::from_ymd_opt(year, field(0).unwrap_or(0), field(2).unwrap_or(0))
+        .and_then(|date| {
+            date.and_hms_nano_opt(
+                field(4).unwrap_or(99),
+                field(6).unwrap_or(99),
+                field(8).unwrap_or(0),
+                nanos,
+            )
+        })
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    match offset {
+        Some(offset) => dt
+            .and_local_timezone(offset)
+            .single()
+            .ok_or_else(|| "custom parsing failed".to_string()),
+        None => with_local_tz(dt),
+    }
+}
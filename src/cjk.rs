@@ -0,0 +1,209 @@
+//! Chinese, Japanese and Korean dates written with unit characters, eg: `2023年8月7日`.
+
+use crate::{with_local_tz, Error};
+use chrono::{DateTime, Datelike, FixedOffset, Local, NaiveDate, Weekday};
+
+/// The fields read from a CJK date, before they are checked
+#[derive(Debug, Default)]
+pub(crate) struct CjkFields {
+    pub(crate) year: Option<i32>,
+    pub(crate) month: Option<u32>,
+    pub(crate) day: Option<u32>,
+    pub(crate) hour: Option<u32>,
+    pub(crate) minute: Option<u32>,
+    pub(crate) second: Option<u32>,
+    pub(crate) weekday: Option<Weekday>,
+    /// `Some(true)` after 午後/下午/오후, `Some(false)` after 午前/上午/오전
+    pub(crate) pm: Option<bool>,
+}
+
+/// Try to parse the following types of CJK dates
+/// 2023年8月7日 8時23分50秒
+/// 2023年8月7日(月) 午後3時
+/// 2023년 8월 7일 오후 3시 23분
+/// 2023年8月7日 下午3:23
+/// ２０２３年８月７日 (full-width digits)
+///
+/// A day of the week in brackets, or written as 星期一/周一/월요일, is checked
+/// against the date. Without a year, the current year is used.
+pub(crate) fn from_cjk_date(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    if !s.contains(['年', '년', '月', '월']) {
+        return Err("custom parsing failed".to_string());
+    }
+    let mut fields = read_fields(s)?;
+    if fields.year.is_none() {
+        fields.year = Some(Local::now().year());
+    }
+    to_datetime(&fields)
+}
+
+/// Read the numbered units, clock times, morning/afternoon markers and the day
+/// of the week. Anything else is an error.
+pub(crate) fn read_fields(s: &str) -> Result<CjkFields, Error> {
+    let chars = s.chars().collect::<Vec<_>>();
+    let mut fields = CjkFields::default();
+    let mut number = String::new();
+    let mut clock = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        let rest = chars[i..].iter().collect::<String>();
+        if c.is_ascii_digit() {
+            number.push(c);
+            i += 1;
+            continue;
+        }
+        if c == ':' && !number.is_empty() {
+            clock.push(take_number(&mut number)?);
+            i += 1;
+            continue;
+        }
+        if !clock.is_empty() && !number.is_empty() {
+            clock.push(take_number(&mut number)?);
+        }
+        if let Some(marker) = ["午前", "上午", "오전", "午後", "下午", "오후"]
+            .iter()
+            .position(|x| rest.starts_with(x))
+        {
+            if !number.is_empty() || fields.pm.is_some() {
+                return Err(format!("unexpected marker in {}", s));
+            }
+            fields.pm = Some(marker >= 3);
+            i += 2;
+            continue;
+        }
+        if number.is_empty() {
+            if let Some((weekday, len)) = weekday_name(&rest) {
+                set(&mut fields.weekday, weekday)?;
+                i += len;
+                continue;
+            }
+        }
+        match c {
+            '年' | '년' => set(&mut fields.year, take_number(&mut number)? as i32)?,
+            '月' | '월' => set(&mut fields.month, take_number(&mut number)?)?,
+            '日' | '일' | '号' | '號' => set(&mut fields.day, take_number(&mut number)?)?,
+            '時' | '时' | '시' | '点' | '點' => {
+                set(&mut fields.hour, take_number(&mut number)?)?
+            }
+            '分' | '분' => set(&mut fields.minute, take_number(&mut number)?)?,
+            '秒' | '초' => set(&mut fields.second, take_number(&mut number)?)?,
+            '半' if number.is_empty() && fields.hour.is_some() => set(&mut fields.minute, 30)?,
+            '(' => {
+                let end = chars[i..]
+                    .iter()
+                    .position(|x| *x == ')')
+                    .ok_or_else(|| format!("unclosed bracket in {}", s))?;
+                let inner = chars[i + 1..i + end].iter().collect::<String>();
+                match weekday_name(inner.trim()) {
+                    Some((weekday, len)) if len == inner.trim().chars().count() => {
+                        set(&mut fields.weekday, weekday)?
+                    }
+                    _ => return Err(format!("unexpected {} in {}", inner, s)),
+                }
+                i += end;
+            }
+            ' ' | ',' | '、' if number.is_empty() => {}
+            _ => return Err(format!("unexpected {} in {}", c, s)),
+        }
+        i += 1;
+    }
+    if !number.is_empty() {
+        if clock.is_empty() {
+            return Err(format!("number without a unit in {}", s));
+        }
+        clock.push(take_number(&mut number)?);
+    }
+    match clock[..] {
+        [] => {}
+        [hour, minute] => {
+            set(&mut fields.hour, hour)?;
+            set(&mut fields.minute, minute)?;
+        }
+        [hour, minute, second] => {
+            set(&mut fields.hour, hour)?;
+            set(&mut fields.minute, minute)?;
+            set(&mut fields.second, second)?;
+        }
+        _ => return Err(format!("invalid time in {}", s)),
+    }
+    Ok(fields)
+}
+
+/// Build the date, in the local timezone, checking the day of the week
+pub(crate) fn to_datetime(fields: &CjkFields) -> Result<DateTime<FixedOffset>, Error> {
+    let (year, month, day) = match (fields.year, fields.month, fields.day) {
+        (Some(year), Some(month), Some(day)) => (year, month, day),
+        _ => return Err("custom parsing failed".to_string()),
+    };
+    let hour = match (fields.hour, fields.pm) {
+        (None, Some(_)) => return Err("morning/afternoon without an hour".to_string()),
+        (Some(hour), Some(_)) if hour == 0 || hour > 12 => {
+            return Err(format!("{} is not a 12-hour clock hour", hour))
+        }
+        (Some(12), Some(false)) => 0,
+        (Some(hour), Some(true)) if hour < 12 => hour + 12,
+        (hour, _) => hour.unwrap_or(0),
+    };
+    let date = NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| format!("invalid date: {}-{}-{}", year, month, day))?;
+    if let Some(weekday) = fields.weekday {
+        if date.weekday() != weekday {
+            return Err(format!("{} is not a {}", date, weekday));
+        }
+    }
+    date.and_hms_opt(hour, fields.minute.unwrap_or(0), fields.second.unwrap_or(0))
+        .ok_or_else(|| "invalid time".to_string())
+        .and_then(with_local_tz)
+}
+
+fn take_number(number: &mut String) -> Result<u32, Error> {
+    let value = number
+        .parse::<u32>()
+        .map_err(|_| "unit without a number".to_string());
+    number.clear();
+    value
+}
+
+fn set<T>(field: &mut Option<T>, value: T) -> Result<(), Error> {
+    match field {
+        Some(_) => Err("field given twice".to_string()),
+        None => {
+            *field = Some(value);
+            Ok(())
+        }
+    }
+}
+
+/// The day of the week at the start of `s` and its length in characters
+/// eg: 月, 月曜日, 월요일, 星期一, 周日
+fn weekday_name(s: &str) -> Option<(Weekday, usize)> {
+    let day = |c: char| match c {
+        '日' | '일' | '天' => Some(Weekday::Sun),
+        '月' | '월' | '一' => Some(Weekday::Mon),
+        '火' | '화' | '二' => Some(Weekday::Tue),
+        '水' | '수' | '三' => Some(Weekday::Wed),
+        '木' | '목' | '四' => Some(Weekday::Thu),
+        '金' | '금' | '五' => Some(Weekday::Fri),
+        '土' | '토' | '六' => Some(Weekday::Sat),
+        _ => None,
+    };
+    for prefix in ["星期", "禮拜", "礼拜", "周", "週"] {
+        if let Some(rest) = s.strip_prefix(prefix) {
+            let weekday = rest.chars().next().and_then(day)?;
+            return Some((weekday, prefix.chars().count() + 1));
+        }
+    }
+    let mut chars = s.chars();
+    let weekday = chars.next().and_then(day)?;
+    let rest = chars.as_str();
+    match ["曜日", "요일", "曜"]
+        .iter()
+        .find(|x| rest.starts_with(**x))
+    {
+        Some(suffix) => Some((weekday, 1 + suffix.chars().count())),
+        // a lone character is only a weekday in brackets
+        None if rest.is_empty() => Some((weekday, 1)),
+        None => None,
+    }
+}
@@ -0,0 +1,20 @@
+//! Web server access-log timestamps (Apache Common Log Format and nginx).
+
+use crate::Error;
+use chrono::{DateTime, FixedOffset};
+
+/// Try to parse the following types of access-log dates, with or without the brackets
+/// [07/Aug/2023:08:23:50 +0200] (Apache CLF `%t`, nginx `$time_local`)
+/// [2023-08-07T08:23:50+02:00] (nginx `$time_iso8601`)
+pub(crate) fn from_common_log_format(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s.trim();
+    let s = s
+        .strip_prefix('[')
+        .and_then(|x| x.strip_suffix(']'))
+        .unwrap_or(s)
+        .trim();
+    DateTime::parse_from_str(s, "%d/%b/%Y:%H:%M:%S %z")
+        .or_else(|_| DateTime::parse_from_str(s, "%d/%b/%Y:%H:%M:%S%.f %z"))
+        .or_else(|_| DateTime::parse_from_rfc3339(s))
+        .map_err(|e| e.to_string())
+}
@@ -0,0 +1,36 @@
+//! Microsoft JSON dates written by ASP.NET AJAX, WCF and `DataContractJsonSerializer`.
+
+use crate::{tz_offset, Error};
+use chrono::{DateTime, FixedOffset};
+
+/// Try to parse the following types of Microsoft JSON dates
+/// /Date(1672903639123)/
+/// /Date(-1672903639123)/
+/// /Date(1672903639123+0200)/
+/// \/Date(1672903639123)\/ (JSON-escaped)
+///
+/// The milliseconds are always since the epoch in UTC; the optional offset only
+/// says at which offset the value is to be shown.
+pub(crate) fn from_ms_json_date(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s.trim().replace("\\/", "/");
+    let inner = s
+        .strip_prefix("/Date(")
+        .and_then(|x| x.strip_suffix(")/"))
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    // the sign of the milliseconds is not the start of the offset
+    let split = inner
+        .char_indices()
+        .skip(1)
+        .find(|(_, c)| ['+', '-'].contains(c))
+        .map_or(inner.len(), |(i, _)| i);
+    let (millis, offset) = inner.split_at(split);
+    let millis = millis.parse::<i64>().map_err(|e| e.to_string())?;
+    let offset = match offset {
+        "" => FixedOffset::east_opt(0),
+        x => tz_offset(x),
+    }
+    .ok_or_else(|| format!("invalid offset: {}", offset))?;
+    DateTime::from_timestamp_millis(millis)
+        .map(|x| x.with_timezone(&offset))
+        .ok_or_else(|| "timestamp out of range".to_string())
+}
@@ -0,0 +1,130 @@
+//! Email header dates following the RFC 5322 grammar, including the obsolete
+//! syntax of section 4.3 that real-world mail still uses.
+
+use crate::{normalize_unicode, tz_offset, DateTimeFixedOffset, Error};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveTime, Offset, Utc, Weekday};
+
+/// Header fields whose value is a date, or ends with one after the final `;`
+const DATE_HEADERS: &[&str] = &["Date", "Resent-Date", "Delivery-Date", "Received"];
+
+/// Parse an email date the way a mail reader has to: with comments, folded
+/// whitespace, an optional day name, two- or three-digit years, optional
+/// seconds and obsolete zone names. It also takes a complete `Date:` or
+/// `Received:` header line, where the date follows the final `;`.
+///
+/// Unknown alphabetic zones, including the military letters, are read as
+/// `-0000` as RFC 5322 asks.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::parse_email_date;
+///
+/// let header = "Received: from mx.example.com by mail.example.org; Mon, 7 Aug 2023 08:23:50 +0200 (CEST)";
+/// let result = parse_email_date(header);
+/// assert_eq!(result.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+/// ```
+pub fn parse_email_date(s: &str) -> Result<DateTimeFixedOffset, Error> {
+    read_email_date(&normalize_unicode(s), true).map(DateTimeFixedOffset)
+}
+
+/// Try to parse the following types of email dates
+/// Mon, 7 Aug 2023 08:23:50 +0200 (CEST)
+/// 7 Aug 23 08:23 EDT
+/// Mon, 7 Aug 2023 08 : 23 : 50 GMT
+/// Received: from a by b; Mon, 7 Aug 2023 08:23:50 +0200
+///
+/// Outside of mail, an unknown zone is more likely a mistake than `-0000`,
+/// so only zones with a known offset are accepted here.
+pub(crate) fn from_email_date(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    read_email_date(s, false)
+}
+
+/// The email date, with unknown alphabetic zones as `-0000` when `lenient`
+fn read_email_date(s: &str, lenient: bool) -> Result<DateTime<FixedOffset>, Error> {
+    let s = strip_comments(&strip_header(s));
+    let s = s
+        .replace(',', " ")
+        .split(':')
+        .map(str::trim)
+        .collect::<Vec<_>>()
+        .join(":");
+    let mut tokens = s.split_whitespace().collect::<Vec<_>>();
+    let weekday = match tokens.first() {
+        Some(x) if x.chars().all(char::is_alphabetic) => {
+            let weekday = x.parse::<Weekday>().map_err(|e| e.to_string())?;
+            tokens.remove(0);
+            Some(weekday)
+        }
+        _ => None,
+    };
+    let [day, month, year, time, zone] = tokens[..] else {
+        return Err("custom parsing failed".to_string());
+    };
+    let year = match (year.len(), year.parse::<i32>()) {
+        // obs-year, RFC 5322 section 4.3
+        (2, Ok(yy)) if yy < 50 => 2000 + yy,
+        (2 | 3, Ok(yy)) => 1900 + yy,
+        (_, Ok(yyyy)) => yyyy,
+        (_, Err(e)) => return Err(e.to_string()),
+    };
+    let date = NaiveDate::parse_from_str(&format!("{} {} {}", day, month, year), "%d %b %Y")
+        .map_err(|e| e.to_string())?;
+    if matches!(weekday, Some(x) if x != date.weekday()) {
+        return Err("day of week does not match the date".to_string());
+    }
+    let time = NaiveTime::parse_from_str(time, "%H:%M:%S")
+        .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M"))
+        .map_err(|e| e.to_string())?;
+    let offset = match tz_offset(zone) {
+        Some(offset) if zone.len() > 1 => offset,
+        // a 12-hour clock, not a zone
+        _ if ["AM", "PM"].contains(&zone.to_ascii_uppercase().as_str()) => {
+            return Err("custom parsing failed".to_string())
+        }
+        _ if lenient && zone.chars().all(|c| c.is_ascii_alphabetic()) => Utc.fix(),
+        _ => return Err(format!("invalid zone: {}", zone)),
+    };
+    date.and_time(time)
+        .and_local_timezone(offset)
+        .single()
+        .ok_or_else(|| "custom parsing failed".to_string())
+}
+
+/// Drop a leading `Date:`-like header name and unfold continuation lines.
+/// For `Received:` only the part after the final `;` is the date.
+fn strip_header(s: &str) -> String {
+    let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+    match s.split_once(':') {
+        Some((name, value))
+            if DATE_HEADERS
+                .iter()
+                .any(|x| x.eq_ignore_ascii_case(name.trim())) =>
+        {
+            value.rsplit(';').next().unwrap_or_default().to_string()
+        }
+        _ => s,
+    }
+}
+
+/// Replace (possibly nested) comments with a space, honouring `\` quoting
+fn strip_comments(s: &str) -> String {
+    let mut depth = 0;
+    let mut escaped = false;
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' if depth > 0 => escaped = true,
+            '(' => depth += 1,
+            ')' if depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    out.push(' ');
+                }
+            }
+            _ if depth == 0 => out.push(c),
+            _ => {}
+        }
+    }
+    out
+}
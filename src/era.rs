@@ -0,0 +1,269 @@
+//! Dates numbered by era rather than by the Gregorian year.
+
+use crate::cjk::{read_fields, to_datetime};
+use crate::{normalize_unicode, with_local_tz, DateTimeFixedOffset, Error};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveTime, Weekday};
+
+/// Japanese eras since the adoption of the Gregorian calendar, with their
+/// romanised initial and first day
+const JAPANESE_ERAS: [(&str, char, (i32, u32, u32)); 5] = [
+    ("明治", 'M', (1868, 10, 23)),
+    ("大正", 'T', (1912, 7, 30)),
+    ("昭和", 'S', (1926, 12, 25)),
+    ("平成", 'H', (1989, 1, 8)),
+    ("令和", 'R', (2019, 5, 1)),
+];
+
+/// Try to parse the following types of Japanese era dates
+/// 令和5年8月7日
+/// 平成31年4月30日 午後3時
+/// 令和元年5月1日 (元年 is the first year of an era)
+/// R5.8.7
+/// H31/4/30 08:23
+///
+/// The date has to fall within the era, so 平成31年5月1日 is an error as
+/// Reiwa began on that day.
+pub(crate) fn from_japanese_era(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s.trim();
+    let (index, abbreviated, rest) = JAPANESE_ERAS
+        .iter()
+        .enumerate()
+        .find_map(|(i, (name, letter, _))| {
+            s.strip_prefix(name)
+                .map(|rest| (i, false, rest))
+                .or_else(|| {
+                    s.strip_prefix(*letter)
+                        .filter(|rest| rest.starts_with(|c: char| c.is_ascii_digit()))
+                        .map(|rest| (i, true, rest))
+                })
+        })
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    let (_, _, (start_year, _, _)) = JAPANESE_ERAS[index];
+    let parsed = if abbreviated {
+        from_abbreviated(rest, start_year)?
+    } else {
+        let rest = match rest.strip_prefix("元年") {
+            Some(rest) => format!("1年{}", rest),
+            None => rest.to_string(),
+        };
+        let mut fields = read_fields(&rest)?;
+        let year = fields.year.ok_or_else(|| "missing era year".to_string())?;
+        fields.year = Some(gregorian_year(start_year, year)?);
+        to_datetime(&fields)?
+    };
+    check_era(index, parsed.date_naive())?;
+    Ok(parsed)
+}
+
+/// `5.8.7`, `31/4/30` or `31-04-30`, optionally followed by a time
+fn from_abbreviated(s: &str, start_year: i32) -> Result<DateTime<FixedOffset>, Error> {
+    let ([year, month, day], _, time) = numeric_date(s)?;
+    let date = NaiveDate::from_ymd_opt(gregorian_year(start_year, year as i32)?, month, day)
+        .ok_or_else(|| format!("invalid date: {}", s))?;
+    with_local_tz(date.and_time(time))
+}
+
+/// The three numbers of a date like `5.8.7` or `07/08/2566`, the number of
+/// digits of the first one, and the time after the date (midnight without one)
+fn numeric_date(s: &str) -> Result<([u32; 3], usize, NaiveTime), Error> {
+    let (date, time) = match s.split_once(' ') {
+        Some((date, time)) => (date, Some(time.trim())),
+        None => (s, None),
+    };
+    let parts = date.split(['.', '/', '-']).collect::<Vec<_>>();
+    let numbers = parts
+        .iter()
+        .map(|x| x.parse::<u32>())
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    let numbers = match numbers[..] {
+        [a, b, c] => [a, b, c],
+        _ => return Err("custom parsing failed".to_string()),
+    };
+    Ok((numbers, parts[0].len(), parse_time(time)?))
+}
+
+/// `08:23:50`, `08:23` or the Thai `08.23 น.`
+fn parse_time(time: Option<&str>) -> Result<NaiveTime, Error> {
+    match time {
+        Some(time) => {
+            let time = time.trim_end_matches("น.").trim();
+            NaiveTime::parse_from_str(time, "%H:%M:%S")
+                .or_else(|_| NaiveTime::parse_from_str(time, "%H:%M"))
+                .or_else(|_| NaiveTime::parse_from_str(time, "%H.%M"))
+                .map_err(|e| e.to_string())
+        }
+        None => Ok(NaiveTime::default()),
+    }
+}
+
+fn gregorian_year(start_year: i32, era_year: i32) -> Result<i32, Error> {
+    if era_year < 1 {
+        return Err(format!("era years start at 1, not {}", era_year));
+    }
+    Ok(start_year + era_year - 1)
+}
+
+/// Check that the date lies between the first day of the era and the next one
+fn check_era(index: usize, date: NaiveDate) -> Result<(), Error> {
+    let first_day = |i: usize| {
+        let (_, _, (year, month, day)) = JAPANESE_ERAS[i];
+        NaiveDate::from_ymd_opt(year, month, day).unwrap_or(NaiveDate::MIN)
+    };
+    let (name, _, _) = JAPANESE_ERAS[index];
+    if date < first_day(index) || (index + 1 < JAPANESE_ERAS.len() && date >= first_day(index + 1))
+    {
+        Err(format!("{} is not in the {} era", date, name))
+    } else {
+        Ok(())
+    }
+}
+
+/// Year numbering for dates that are Gregorian apart from the year
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Era {
+    /// Thai Buddhist Era, 543 years ahead: 2566 is 2023
+    Buddhist,
+    /// Republic of China (Minguo) years, counted from 1912: 112 is 2023
+    Minguo,
+}
+
+/// Thai month names and their abbreviations
+const THAI_MONTHS: [(&str, &str); 12] = [
+    ("มกราคม", "ม.ค."),
+    ("กุมภาพันธ์", "ก.พ."),
+    ("มีนาคม", "มี.ค."),
+    ("เมษายน", "เม.ย."),
+    ("พฤษภาคม", "พ.ค."),
+    ("มิถุนายน", "มิ.ย."),
+    ("กรกฎาคม", "ก.ค."),
+    ("สิงหาคม", "ส.ค."),
+    ("กันยายน", "ก.ย."),
+    ("ตุลาคม", "ต.ค."),
+    ("พฤศจิกายน", "พ.ย."),
+    ("ธันวาคม", "ธ.ค."),
+];
+
+/// Thai weekday names from Monday to Sunday
+const THAI_WEEKDAYS: [&str; 7] = ["จันทร์", "อังคาร", "พุธ", "พฤหัสบดี", "ศุกร์", "เสาร์", "อาทิตย์"];
+
+/// Parse a date whose year is numbered in the given era. Besides the forms
+/// that name the era or the month (see the general parser), numeric dates are
+/// read as day/month/year for the Buddhist Era, unless they start with the
+/// year, and as year/month/day for Minguo years.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::{parse_with_era, Era};
+///
+/// let result = parse_with_era("07/08/2566", Era::Buddhist);
+/// assert!(result.unwrap().0.to_rfc3339().starts_with("2023-08-07T00:00:00"));
+///
+/// let result = parse_with_era("112/08/07", Era::Minguo);
+/// assert!(result.unwrap().0.to_rfc3339().starts_with("2023-08-07T00:00:00"));
+/// ```
+pub fn parse_with_era(s: &str, era: Era) -> Result<DateTimeFixedOffset, Error> {
+    let s = normalize_unicode(s.trim());
+    match era {
+        Era::Buddhist => from_thai(&s).or_else(|_| {
+            let ([a, b, c], digits, time) = numeric_date(&s)?;
+            let (year, month, day) = if digits == 4 { (a, b, c) } else { (c, b, a) };
+            buddhist_date(year as i32, month, day, time)
+        }),
+        Era::Minguo => from_minguo(&s).or_else(|_| {
+            let ([year, month, day], _, time) = numeric_date(&s)?;
+            NaiveDate::from_ymd_opt(gregorian_year(1912, year as i32)?, month, day)
+                .ok_or_else(|| format!("invalid date: {}", s))
+                .and_then(|x| with_local_tz(x.and_time(time)))
+        }),
+    }
+    .map(DateTimeFixedOffset)
+}
+
+/// Dates that name their era, for the general parser
+/// eg: 民國112年8月7日, 7 ส.ค. 2566, 7 สิงหาคม พ.ศ. 2566
+pub(crate) fn from_era_year(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s.trim();
+    from_minguo(s).or_else(|_| from_thai(s))
+}
+
+/// Minguo dates, eg: 民國112年8月7日, 中華民國112年8月7日 下午3時
+fn from_minguo(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let rest = ["中華民國", "中华民国", "民國", "民国"]
+        .iter()
+        .find_map(|x| s.strip_prefix(x))
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    let rest = match rest.trim_start().strip_prefix("元年") {
+        Some(rest) => format!("1年{}", rest),
+        None => rest.trim_start().to_string(),
+    };
+    let mut fields = read_fields(&rest)?;
+    let year = fields
+        .year
+        .ok_or_else(|| "missing Minguo year".to_string())?;
+    fields.year = Some(gregorian_year(1912, year)?);
+    to_datetime(&fields)
+}
+
+/// Thai dates with a month name, in the Buddhist Era unless marked ค.ศ. (CE)
+/// eg: 7 ส.ค. 2566, วันจันทร์ที่ 7 สิงหาคม พ.ศ. 2566 เวลา 08:23 น.
+fn from_thai(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let common_era = s.contains("ค.ศ.");
+    let s = s
+        .replace("พ.ศ.", " ")
+        .replace("ค.ศ.", " ")
+        .replace("เวลา", " ");
+    let mut tokens = s.split_whitespace().peekable();
+    let weekday = match tokens.peek().and_then(|x| x.strip_prefix("วัน")) {
+        Some(name) => {
+            let name = name.trim_end_matches("ที่");
+            let weekday = THAI_WEEKDAYS
+                .iter()
+                .position(|x| *x == name)
+                .and_then(|x| Weekday::try_from(x as u8).ok())
+                .ok_or_else(|| format!("unknown weekday: {}", name))?;
+            tokens.next();
+            Some(weekday)
+        }
+        None => None,
+    };
+    let (day, month, year) = match (tokens.next(), tokens.next(), tokens.next()) {
+        (Some(day), Some(month), Some(year)) => (day, month, year),
+        _ => return Err("custom parsing failed".to_string()),
+    };
+    let month = THAI_MONTHS
+        .iter()
+        .position(|(full, short)| *full == month || *short == month)
+        .ok_or_else(|| "custom parsing failed".to_string())? as u32
+        + 1;
+    let day = day.parse::<u32>().map_err(|e| e.to_string())?;
+    let year = year.parse::<i32>().map_err(|e| e.to_string())?;
+    let rest = tokens.collect::<Vec<_>>().join(" ");
+    let time = parse_time(Some(rest.as_str()).filter(|x| !x.is_empty()))?;
+    let parsed = if common_era {
+        NaiveDate::from_ymd_opt(year, month, day)
+            .ok_or_else(|| format!("invalid date: {}", s))
+            .and_then(|x| with_local_tz(x.and_time(time)))
+    } else {
+        buddhist_date(year, month, day, time)
+    }?;
+    match weekday {
+        Some(weekday) if parsed.weekday() != weekday => {
+            Err(format!("{} is not a {}", parsed.date_naive(), weekday))
+        }
+        _ => Ok(parsed),
+    }
+}
+
+/// A Buddhist Era date, where a two-digit year is short for 25xx
+fn buddhist_date(
+    year: i32,
+    month: u32,
+    day: u32,
+    time: NaiveTime,
+) -> Result<DateTime<FixedOffset>, Error> {
+    let year = if year < 100 { year + 2500 } else { year };
+    NaiveDate::from_ymd_opt(year - 543, month, day)
+        .ok_or_else(|| format!("invalid date: {}-{}-{} BE", year, month, day))
+        .and_then(|x| with_local_tz(x.and_time(time)))
+}
@@ -0,0 +1,97 @@
+//! Photo and video metadata dates (EXIF, QuickTime and XMP).
+//! QuickTime `2023-08-07T08:23:50+0200` is already handled by the main chain.
+
+use crate::{normalize_unicode, parse_from, tz_offset, with_local_tz, DateTimeFixedOffset, Error};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+
+/// Try to parse the following types of media dates
+/// 2023:08:07 08:23:50 (EXIF DateTimeOriginal)
+/// 2023:08:07 08:23:50.123
+/// 2023-08 (XMP, partial date)
+/// 2023-08-07T08:23 (XMP, no seconds)
+/// 2023-08-07T08:23+02:00
+pub(crate) fn from_media_date(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    from_exif_datetime(s)
+        .and_then(with_local_tz)
+        .or_else(|_| from_xmp_date(s))
+}
+
+/// EXIF writes the date with colons, and fills unknown fields with spaces
+fn from_exif_datetime(s: &str) -> Result<NaiveDateTime, Error> {
+    NaiveDateTime::parse_from_str(s.trim(), "%Y:%m:%d %H:%M:%S")
+        .or_else(|_| NaiveDateTime::parse_from_str(s.trim(), "%Y:%m:%d %H:%M:%S%.f"))
+        .map_err(|e| e.to_string())
+}
+
+/// Parse an XMP date, which may be shortened down to the year. A bare year
+/// such as `2023` looks like a unix timestamp to the general parser, so it
+/// is only read as the first of January here.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::parse_xmp;
+///
+/// let result = parse_xmp("2023");
+/// assert!(result.unwrap().0.to_rfc3339().starts_with("2023-01-01T00:00:00"));
+/// ```
+pub fn parse_xmp(s: &str) -> Result<DateTimeFixedOffset, Error> {
+    let s = normalize_unicode(s);
+    let s = s.trim();
+    if s.len() == 4 && s.chars().all(|c| c.is_ascii_digit()) {
+        return NaiveDate::parse_from_str(&format!("{}-01-01", s), "%Y-%m-%d")
+            .map_err(|e| e.to_string())
+            .and_then(|x| with_local_tz(x.and_time(Default::default())))
+            .map(DateTimeFixedOffset);
+    }
+    // full dates and times are plain ISO 8601
+    from_xmp_date(s)
+        .or_else(|_| parse_from(s))
+        .map(DateTimeFixedOffset)
+}
+
+/// XMP allows shortened ISO 8601 dates and minutes without seconds
+/// eg: 2023-08, 2023-08-07T08:23, 2023-08-07T08:23+02:00
+fn from_xmp_date(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    // YYYY-MM, always with the year in full
+    if s.len() == 7 && s.find('-') == Some(4) {
+        if let Ok(date) = NaiveDate::parse_from_str(&format!("{}-01", s), "%Y-%m-%d") {
+            return with_local_tz(date.and_time(Default::default()));
+        }
+    }
+    let zoned = match s.strip_suffix('Z') {
+        Some(s) => format!("{}+00:00", s),
+        None => s.to_string(),
+    };
+    DateTime::parse_from_str(&zoned, "%Y-%m-%dT%H:%M%:z")
+        .map_err(|e| e.to_string())
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(s, "%Y-%m-%dT%H:%M")
+                .map_err(|e| e.to_string())
+                .and_then(with_local_tz)
+        })
+}
+
+/// Combine an EXIF date/time (`DateTimeOriginal`, `DateTimeDigitized`, `DateTime`)
+/// with its separate offset tag (`OffsetTimeOriginal`, `OffsetTimeDigitized`,
+/// `OffsetTime`). When the offset is blank, as EXIF writes unknown values,
+/// the local timezone is used.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::parse_exif;
+///
+/// let result = parse_exif("2023:08:07 08:23:50", "+02:00");
+/// assert_eq!(result.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+/// ```
+pub fn parse_exif(datetime: &str, offset: &str) -> Result<DateTimeFixedOffset, Error> {
+    let dt = from_exif_datetime(&normalize_unicode(datetime))?;
+    let offset = normalize_unicode(offset);
+    let offset = offset.trim();
+    if offset.is_empty() || offset == ":" {
+        return with_local_tz(dt).map(DateTimeFixedOffset);
+    }
+    tz_offset(&offset.replace(':', ""))
+        .and_then(|x| dt.and_local_timezone(x).single())
+        .map(DateTimeFixedOffset)
+        .ok_or_else(|| format!("invalid offset: {}", offset))
+}
@@ -0,0 +1,107 @@
+//! Timestamps as stored and printed by git (`git log --date=<format>`).
+//!
+//! `short`, `iso`, `iso-strict`, `rfc`, `unix` and `local` (asctime in local
+//! time) are already understood by the main chain. `format:` is free-form
+//! strftime and can only be parsed when it happens to produce one of the
+//! supported formats.
+
+use crate::{tz_offset, with_local_tz, Error};
+use chrono::{DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, NaiveTime, Weekday};
+
+/// Try to parse the following types of git dates
+/// 1672903639 +0200 (raw)
+/// Mon Aug 7 08:23:50 2023 +0200 (default)
+/// 3 days ago, 2 years, 5 months ago (relative)
+/// Mon 08:23, Sat 08:23 +0200 (human, for commits of the last week)
+/// Fri Aug 7 08:23 (human, for older commits of this year)
+pub(crate) fn from_git_date(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+    from_raw(&s)
+        .or_else(|_| DateTime::parse_from_str(&s, "%a %b %e %T %Y %z").map_err(|e| e.to_string()))
+        .or_else(|_| from_relative(&s))
+        .or_else(|_| from_weekday_time(&s))
+}
+
+/// Seconds since the epoch followed by the committer's offset
+/// eg: 1672903639 +0200
+fn from_raw(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let (secs, tz) = s
+        .split_once(' ')
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    let secs = secs.parse::<i64>().map_err(|e| e.to_string())?;
+    let offset = tz_offset(tz).ok_or_else(|| "custom parsing failed".to_string())?;
+    DateTime::from_timestamp(secs, 0)
+        .map(|x| x.with_timezone(&offset))
+        .ok_or_else(|| "timestamp out of range".to_string())
+}
+
+/// Relative dates, counted back from now. Git rounds months to 30 days and
+/// years to 365 days, so the result is only as precise as the input.
+/// eg: 5 minutes ago, 3 weeks ago, 2 years, 5 months ago
+fn from_relative(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s
+        .strip_suffix(" ago")
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    let mut ago = Duration::zero();
+    for part in s.split(", ") {
+        let (n, unit) = part
+            .split_once(' ')
+            .ok_or_else(|| "custom parsing failed".to_string())?;
+        let n = n.parse::<i64>().map_err(|e| e.to_string())?;
+        ago += match unit.trim_end_matches('s') {
+            "second" => Duration::seconds(n),
+            "minute" => Duration::minutes(n),
+            "hour" => Duration::hours(n),
+            "day" => Duration::days(n),
+            "week" => Duration::weeks(n),
+            "month" => Duration::days(n * 30),
+            "year" => Duration::days(n * 365),
+            _ => return Err("custom parsing failed".to_string()),
+        };
+    }
+    Ok(Local::now().fixed_offset() - ago)
+}
+
+/// `--date=human` prints the weekday and time for commits of the last few
+/// days, so use the most recent such weekday, and adds the month and day for
+/// older commits of the current year. The offset is only printed when it
+/// differs from the local one.
+/// eg: Mon 08:23, Sat 08:23 +0200, Fri Aug 7 08:23
+fn from_weekday_time(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let tokens = s.split_whitespace().collect::<Vec<_>>();
+    let (weekday, date, time, tz) = match tokens[..] {
+        [weekday, time] => (weekday, None, time, None),
+        [weekday, time, tz] if tz.starts_with(['+', '-']) => (weekday, None, time, Some(tz)),
+        [weekday, month, day, time] => (weekday, Some((month, day)), time, None),
+        [weekday, month, day, time, tz] => (weekday, Some((month, day)), time, Some(tz)),
+        _ => return Err("custom parsing failed".to_string()),
+    };
+    let weekday = weekday.parse::<Weekday>().map_err(|e| e.to_string())?;
+    let time = NaiveTime::parse_from_str(time, "%H:%M").map_err(|e| e.to_string())?;
+    let today = Local::now().date_naive();
+    let date = match date {
+        Some((month, day)) => {
+            let date = NaiveDate::parse_from_str(
+                &format!("{} {} {}", month, day, today.year()),
+                "%b %d %Y",
+            )
+            .map_err(|e| e.to_string())?;
+            if date.weekday() != weekday {
+                return Err(format!("{} is not a {}", date, weekday));
+            }
+            date
+        }
+        None => {
+            let days_back =
+                (today.weekday().num_days_from_monday() + 7 - weekday.num_days_from_monday()) % 7;
+            today - Duration::days(days_back as i64)
+        }
+    };
+    match tz {
+        Some(tz) => tz_offset(tz)
+            .filter(|_| tz.starts_with(['+', '-']))
+            .and_then(|offset| date.and_time(time).and_local_timezone(offset).single())
+            .ok_or_else(|| format!("invalid offset: {}", tz)),
+        None => with_local_tz(date.and_time(time)),
+    }
+}
@@ -0,0 +1,231 @@
+//! Hebrew calendar dates, eg: `14 Av 5783` or `י״ד באב תשפ״ג`.
+
+use crate::{with_local_tz, Error};
+use chrono::{DateTime, FixedOffset, NaiveDate};
+
+/// Month names, transliterated and in Hebrew, numbered from Nisan as in the
+/// Bible; Adar is 12 and Adar II is 13. Plain `Adar` is 0 until the year says
+/// which Adar it is.
+const MONTHS: [(&str, u32); 40] = [
+    ("nisan", 1),
+    ("ניסן", 1),
+    ("iyar", 2),
+    ("iyyar", 2),
+    ("אייר", 2),
+    ("sivan", 3),
+    ("סיון", 3),
+    ("סיוון", 3),
+    ("tammuz", 4),
+    ("tamuz", 4),
+    ("תמוז", 4),
+    ("av", 5),
+    ("menachem av", 5),
+    ("אב", 5),
+    ("מנחם אב", 5),
+    ("elul", 6),
+    ("אלול", 6),
+    ("tishrei", 7),
+    ("tishri", 7),
+    ("תשרי", 7),
+    ("cheshvan", 8),
+    ("heshvan", 8),
+    ("marcheshvan", 8),
+    ("חשון", 8),
+    ("חשוון", 8),
+    ("מרחשון", 8),
+    ("מרחשוון", 8),
+    ("kislev", 9),
+    ("כסלו", 9),
+    ("כסליו", 9),
+    ("tevet", 10),
+    ("teveth", 10),
+    ("טבת", 10),
+    ("shevat", 11),
+    ("shvat", 11),
+    ("שבט", 11),
+    ("adar", 0),
+    ("אדר", 0),
+    ("adar i", 12),
+    ("adar ii", 13),
+];
+
+/// Other ways to write Adar I and Adar II, after the geresh is dropped
+const ADARS: [(&str, u32); 12] = [
+    ("adar 1", 12),
+    ("adar aleph", 12),
+    ("adar rishon", 12),
+    ("אדר א", 12),
+    ("אדר ראשון", 12),
+    ("אדר 1", 12),
+    ("adar 2", 13),
+    ("adar bet", 13),
+    ("adar sheni", 13),
+    ("אדר ב", 13),
+    ("אדר שני", 13),
+    ("אדר 2", 13),
+];
+
+/// The day before 1 Tishrei 1 AM, in days since 1 January 1 CE (day 1)
+const EPOCH: i64 = -1373428;
+
+const TISHREI: u32 = 7;
+
+/// Try to parse the following types of Hebrew calendar dates
+/// 14 Av 5783
+/// 14 Adar II 5784
+/// י״ד באב תשפ״ג (Hebrew numerals, with or without gershayim)
+/// 14 באדר ב׳ ה׳תשפ״ד
+///
+/// A Hebrew year without thousands is in the sixth millennium. Plain `Adar`
+/// in a leap year is Adar II, as for Purim; Adar I and Adar II are only
+/// valid in leap years. The day is the civil day from midnight, not from sunset.
+pub(crate) fn from_hebrew(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let normalized = s
+        .trim()
+        .replace(['"', '״'], "")
+        .replace(['\'', '׳'], "'")
+        .to_lowercase();
+    let tokens = normalized.split_whitespace().collect::<Vec<_>>();
+    if tokens.len() < 3 {
+        return Err("custom parsing failed".to_string());
+    }
+    let name = tokens[1..tokens.len() - 1].join(" ").replace('\'', "");
+    // Hebrew writes "in Av", eg: באב
+    let month = month_number(&name)
+        .or_else(|| name.strip_prefix('ב').and_then(month_number))
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    let day = hebrew_number(tokens[0])?;
+    let year = hebrew_number(tokens[tokens.len() - 1])?;
+    let year = if year < 1000 && !tokens[tokens.len() - 1].contains(|c: char| c.is_ascii_digit()) {
+        year + 5000
+    } else {
+        year
+    };
+    let month = match month {
+        0 if is_leap_year(year) => 13,
+        0 => 12,
+        12 | 13 if !is_leap_year(year) => {
+            return Err(format!("{} is not a leap year with two Adars", year))
+        }
+        month => month,
+    };
+    let date = to_gregorian(year as i64, month, day)?;
+    with_local_tz(date.and_time(Default::default()))
+}
+
+fn month_number(name: &str) -> Option<u32> {
+    MONTHS
+        .iter()
+        .chain(ADARS.iter())
+        .find(|(x, _)| *x == name)
+        .map(|(_, month)| *month)
+}
+
+/// A number in ASCII digits or in Hebrew letters, eg: `14`, `יד`, `ה'תשפג`.
+/// In letters, a geresh after the first letter of a longer number marks thousands.
+fn hebrew_number(s: &str) -> Result<u32, Error> {
+    if let Ok(x) = s.parse::<u32>() {
+        return Ok(x);
+    }
+    let (thousands, rest) = match s.char_indices().nth(1) {
+        Some((i, '\'')) if s.len() > i + 1 => (letter_value(s.chars().next())? * 1000, &s[i + 1..]),
+        _ => (0, s),
+    };
+    rest.chars()
+        .filter(|c| *c != '\'')
+        .try_fold(thousands, |total, c| Ok(total + letter_value(Some(c))?))
+}
+
+fn letter_value(c: Option<char>) -> Result<u32, Error> {
+    match c {
+        Some('א') => Ok(1),
+        Some('ב') => Ok(2),
+        Some('ג') => Ok(3),
+        Some('ד') => Ok(4),
+        Some('ה') => Ok(5),
+        Some('ו') => Ok(6),
+        Some('ז') => Ok(7),
+        Some('ח') => Ok(8),
+        Some('ט') => Ok(9),
+        Some('י') => Ok(10),
+        Some('כ' | 'ך') => Ok(20),
+        Some('ל') => Ok(30),
+        Some('מ' | 'ם') => Ok(40),
+        Some('נ' | 'ן') => Ok(50),
+        Some('ס') => Ok(60),
+        Some('ע') => Ok(70),
+        Some('פ' | 'ף') => Ok(80),
+        Some('צ' | 'ץ') => Ok(90),
+        Some('ק') => Ok(100),
+        Some('ר') => Ok(200),
+        Some('ש') => Ok(300),
+        Some('ת') => Ok(400),
+        _ => Err("custom parsing failed".to_string()),
+    }
+}
+
+fn is_leap_year(year: u32) -> bool {
+    (7 * year as i64 + 1) % 19 < 7
+}
+
+/// Days from the epoch to the molad of Tishrei, postponed when it falls on a
+/// Sunday, Wednesday or Friday
+fn elapsed_days(year: i64) -> i64 {
+    let months = (235 * year - 234).div_euclid(19);
+    let parts = 12084 + 13753 * months;
+    let day = 29 * months + parts.div_euclid(25920);
+    if (3 * (day + 1)).rem_euclid(7) < 3 {
+        day + 1
+    } else {
+        day
+    }
+}
+
+/// The first day of the year, after the postponements that keep years
+/// between 353 and 385 days long
+fn new_year(year: i64) -> i64 {
+    let delay = if elapsed_days(year + 1) - elapsed_days(year) == 356 {
+        2
+    } else if elapsed_days(year) - elapsed_days(year - 1) == 382 {
+        1
+    } else {
+        0
+    };
+    EPOCH + elapsed_days(year) + delay
+}
+
+fn month_length(year: i64, month: u32) -> u32 {
+    let year_length = new_year(year + 1) - new_year(year);
+    match month {
+        2 | 4 | 6 | 10 | 13 => 29,
+        12 if !is_leap_year(year as u32) => 29,
+        8 if year_length % 10 != 5 => 29,
+        9 if year_length % 10 == 3 => 29,
+        _ => 30,
+    }
+}
+
+/// Convert a Hebrew date to the Gregorian calendar
+fn to_gregorian(year: i64, month: u32, day: u32) -> Result<NaiveDate, Error> {
+    if year < 1 || day < 1 || day > month_length(year, month) {
+        return Err(format!(
+            "{} {} {} is not a valid Hebrew date",
+            day, month, year
+        ));
+    }
+    let last_month = if is_leap_year(year as u32) { 13 } else { 12 };
+    // the year starts with Tishrei, the seventh month
+    let months_before = if month < TISHREI {
+        (TISHREI..=last_month).chain(1..month).collect::<Vec<_>>()
+    } else {
+        (TISHREI..month).collect::<Vec<_>>()
+    };
+    let days = months_before
+        .into_iter()
+        .map(|x| month_length(year, x) as i64)
+        .sum::<i64>();
+    i32::try_from(new_year(year) + days + day as i64)
+        .ok()
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .ok_or_else(|| "date out of range".to_string())
+}
@@ -0,0 +1,222 @@
+//! Islamic (Hijri) calendar dates, eg: `15 Muharram 1445` or `1445-01-15 AH`.
+
+use crate::{normalize_unicode, with_local_tz, DateTimeFixedOffset, Error};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate};
+
+/// Month names, transliterated and in Arabic, in the form they are compared in:
+/// lowercase, without apostrophes, with hyphens as spaces
+const MONTHS: [&[&str]; 12] = [
+    &["muharram", "al muharram", "محرم"],
+    &["safar", "صفر"],
+    &[
+        "rabi al awwal",
+        "rabi ul awwal",
+        "rabi i",
+        "ربيع الأول",
+        "ربيع الاول",
+    ],
+    &[
+        "rabi al thani",
+        "rabi al akhir",
+        "rabi us sani",
+        "rabi ii",
+        "ربيع الآخر",
+        "ربيع الثاني",
+    ],
+    &[
+        "jumada al awwal",
+        "jumada al ula",
+        "jumada i",
+        "جمادى الأولى",
+        "جمادى الاولى",
+        "جمادى الأول",
+    ],
+    &[
+        "jumada al thani",
+        "jumada al akhirah",
+        "jumada al akhira",
+        "jumada ii",
+        "جمادى الآخرة",
+        "جمادى الثانية",
+    ],
+    &["rajab", "رجب"],
+    &["shaban", "شعبان"],
+    &["ramadan", "ramazan", "رمضان"],
+    &["shawwal", "شوال"],
+    &[
+        "dhu al qadah",
+        "dhu al qidah",
+        "dhul qadah",
+        "dhul qidah",
+        "ذو القعدة",
+    ],
+    &["dhu al hijjah", "dhul hijjah", "ذو الحجة"],
+];
+
+/// Julian day number of 1 Muharram 1 AH in the tabular calendar (16 July 622)
+const EPOCH: i64 = 1948440;
+
+/// Days between the Julian day number and chrono's day count from 1 January 1 CE
+const JDN_CE: i64 = 1721425;
+
+/// How Hijri dates are converted to Gregorian ones
+#[derive(Debug, Clone, Copy)]
+pub enum HijriCalendar<'a> {
+    /// the arithmetic calendar with leap years 2, 5, 7, 10, 13, 16, 18, 21, 24,
+    /// 26 and 29 of each 30-year cycle
+    Tabular,
+    /// the month lengths of a Umm al-Qura table, as published for Saudi Arabia
+    UmmAlQura(&'a UmmAlQuraTable),
+}
+
+/// Observed month lengths from a given Hijri year onwards, as the Umm al-Qura
+/// calendar cannot be computed
+#[derive(Debug, Clone, PartialEq)]
+pub struct UmmAlQuraTable {
+    first_year: i32,
+    start: NaiveDate,
+    month_lengths: Vec<u8>,
+}
+
+impl UmmAlQuraTable {
+    /// The table for the months from 1 Muharram of `first_year`, which fell on
+    /// `start`. Every month has to be 29 or 30 days long.
+    pub fn new(first_year: i32, start: NaiveDate, month_lengths: Vec<u8>) -> Result<Self, Error> {
+        match month_lengths.iter().find(|x| !(29..=30).contains(*x)) {
+            Some(length) => Err(format!("a month cannot be {} days long", length)),
+            None => Ok(UmmAlQuraTable {
+                first_year,
+                start,
+                month_lengths,
+            }),
+        }
+    }
+
+    fn to_gregorian(&self, year: i32, month: u32, day: u32) -> Result<NaiveDate, Error> {
+        let index = (year - self.first_year) as i64 * 12 + month as i64 - 1;
+        let length = usize::try_from(index)
+            .ok()
+            .and_then(|x| self.month_lengths.get(x))
+            .ok_or_else(|| format!("{}/{} AH is not in the Umm al-Qura table", month, year))?;
+        if day < 1 || day > *length as u32 {
+            return Err(format!("{}/{}/{} AH is not a valid date", day, month, year));
+        }
+        let days = self.month_lengths[..index as usize]
+            .iter()
+            .map(|x| *x as i64)
+            .sum::<i64>();
+        Ok(self.start + Duration::days(days + day as i64 - 1))
+    }
+}
+
+/// Parse a Hijri date, with the month named or as a number.
+/// Numeric dates are year-month-day when they start with the year,
+/// and day/month/year otherwise; `AH` or `هـ` after the date is optional.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::{parse_hijri, HijriCalendar};
+///
+/// let result = parse_hijri("15 Muharram 1445", HijriCalendar::Tabular);
+/// assert!(result.unwrap().0.to_rfc3339().starts_with("2023-08-02T00:00:00"));
+///
+/// let result = parse_hijri("1445-01-15", HijriCalendar::Tabular);
+/// assert!(result.unwrap().0.to_rfc3339().starts_with("2023-08-02T00:00:00"));
+/// ```
+pub fn parse_hijri(s: &str, calendar: HijriCalendar) -> Result<DateTimeFixedOffset, Error> {
+    let (year, month, day, _) = read_hijri(&normalize_unicode(s))?;
+    let date = match calendar {
+        HijriCalendar::Tabular => tabular_to_gregorian(year, month, day)?,
+        HijriCalendar::UmmAlQura(table) => table.to_gregorian(year, month, day)?,
+    };
+    with_local_tz(date.and_time(Default::default())).map(DateTimeFixedOffset)
+}
+
+/// Hijri dates in the tabular calendar, for the general parser. They have to
+/// name the month or be marked `AH`/`هـ`.
+/// eg: 15 Muharram 1445, 1445-01-15 AH, ١٥ محرم ١٤٤٥ هـ
+pub(crate) fn from_hijri(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    match read_hijri(s) {
+        Ok((year, month, day, true)) => {
+            with_local_tz(tabular_to_gregorian(year, month, day)?.and_time(Default::default()))
+        }
+        _ => Err("custom parsing failed".to_string()),
+    }
+}
+
+/// The year, month and day of a Hijri date, and whether it says it is one
+fn read_hijri(s: &str) -> Result<(i32, u32, u32, bool), Error> {
+    let mut s = s
+        .to_lowercase()
+        .replace(['\'', '’', 'ʿ', 'ʾ', '`'], "")
+        .replace(['-', ','], " ");
+    let mut marked = false;
+    for marker in ["a.h.", "ah", "هـ", "ه"] {
+        let rest = s.trim_end().strip_suffix(marker);
+        if let Some(rest) = rest.filter(|x| x.ends_with(|c: char| c.is_ascii_digit() || c == ' ')) {
+            s = rest.to_string();
+            marked = true;
+            break;
+        }
+    }
+    let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+    let named = (0..12).find_map(|i| {
+        MONTHS[i]
+            .iter()
+            .find_map(|name| find_word(&s, name))
+            .map(|(start, end)| (i as u32 + 1, start, end))
+    });
+    let numbers = |s: &str| {
+        s.split([' ', '/', '.'])
+            .filter(|x| !x.is_empty())
+            .map(|x| x.parse::<u32>().map_err(|e| e.to_string()))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    match named {
+        Some((month, start, end)) => {
+            let rest = format!("{} {}", &s[..start], &s[end..]);
+            match numbers(&rest)?[..] {
+                [day, year] => Ok((year as i32, month, day, true)),
+                _ => Err("custom parsing failed".to_string()),
+            }
+        }
+        None => {
+            let first = s.split([' ', '/', '.']).next().unwrap_or_default();
+            match numbers(&s)?[..] {
+                [year, month, day] if first.len() == 4 => Ok((year as i32, month, day, marked)),
+                [day, month, year] => Ok((year as i32, month, day, marked)),
+                _ => Err("custom parsing failed".to_string()),
+            }
+        }
+    }
+}
+
+/// The byte range of `word` in `s`, when it is not part of a longer word
+fn find_word(s: &str, word: &str) -> Option<(usize, usize)> {
+    s.match_indices(word)
+        .map(|(i, _)| (i, i + word.len()))
+        .find(|(start, end)| {
+            !s[..*start].ends_with(char::is_alphabetic)
+                && !s[*end..].starts_with(char::is_alphabetic)
+        })
+}
+
+/// Convert a date of the tabular Islamic calendar
+fn tabular_to_gregorian(year: i32, month: u32, day: u32) -> Result<NaiveDate, Error> {
+    let leap = (14 + 11 * year as i64).rem_euclid(30) < 11;
+    let length = match month {
+        12 if leap => 30,
+        1..=12 if month % 2 == 1 => 30,
+        1..=12 => 29,
+        _ => 0,
+    };
+    if year < 1 || day < 1 || day > length {
+        return Err(format!("{}/{}/{} AH is not a valid date", day, month, year));
+    }
+    let (year, month) = (year as i64, month as i64);
+    let jdn =
+        day as i64 + (59 * (month - 1) + 1) / 2 + (year - 1) * 354 + (3 + 11 * year) / 30 + EPOCH
+            - 1;
+    NaiveDate::from_num_days_from_ce_opt((jdn - JDN_CE) as i32)
+        .ok_or_else(|| "date out of range".to_string())
+}
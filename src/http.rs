@@ -0,0 +1,108 @@
+//! HTTP-date parsing as defined in RFC 9110 section 5.6.7.
+//! All three HTTP-date forms are always in GMT. The asctime form carries no
+//! zone at all, so only the HTTP functions read it as GMT; the general parser
+//! reads it as local time.
+
+use crate::{normalize_unicode, DateTimeFixedOffset, Error};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDateTime, Utc};
+
+/// Try to parse the following types of HTTP dates
+/// Sun, 06 Nov 1994 08:49:37 GMT (IMF-fixdate)
+/// Sunday, 06-Nov-94 08:49:37 GMT (RFC 850)
+/// Wed, 07-Aug-2023 08:23:50 GMT (Netscape cookie `Expires`)
+pub(crate) fn from_http_date(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    read_http_date(s, false)
+}
+
+/// The HTTP date, also in the asctime form when `asctime`
+/// eg: Sun Nov  6 08:49:37 1994
+fn read_http_date(s: &str, asctime: bool) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+    NaiveDateTime::parse_from_str(&s, "%a, %d %b %Y %T GMT")
+        .or_else(|e| {
+            if asctime {
+                NaiveDateTime::parse_from_str(&s, "%a %b %e %T %Y")
+            } else {
+                Err(e)
+            }
+        })
+        .map_err(|e| e.to_string())
+        .or_else(|_| from_rfc850(&s))
+        .or_else(|_| from_cookie_date(&s))
+        .map(|x| x.and_utc().fixed_offset())
+}
+
+/// RFC 850 dates only carry a two-digit year, which RFC 9110 says to read as
+/// the most recent year with those digits that is not more than 50 years ahead.
+/// eg: Sunday, 06-Nov-94 08:49:37 GMT
+fn from_rfc850(s: &str) -> Result<NaiveDateTime, Error> {
+    let tokens = s.split(' ').collect::<Vec<_>>();
+    if tokens.len() != 4 {
+        return Err("custom parsing failed".to_string());
+    }
+    let (day_month, yy) = tokens[1]
+        .rsplit_once('-')
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    if yy.len() != 2 || !yy.chars().all(|c| c.is_ascii_digit()) {
+        return Err("custom parsing failed".to_string());
+    }
+    let this_year = Utc::now().year();
+    let mut year = this_year - this_year % 100 + yy.parse::<i32>().map_err(|e| e.to_string())?;
+    if year > this_year + 50 {
+        year -= 100;
+    }
+    let date = format!(
+        "{} {}-{} {} {}",
+        tokens[0], day_month, year, tokens[2], tokens[3]
+    );
+    NaiveDateTime::parse_from_str(&date, "%A, %d-%b-%Y %T GMT").map_err(|e| e.to_string())
+}
+
+/// Cookie dates are parsed the way browsers do (RFC 6265 section 5.1.1),
+/// which ignores the day name instead of checking it against the date.
+/// eg: Wed, 07-Aug-2023 08:23:50 GMT
+fn from_cookie_date(s: &str) -> Result<NaiveDateTime, Error> {
+    let (_, date) = s
+        .split_once(", ")
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    if date.split(['-', ' ']).nth(2).map(str::len) != Some(4) {
+        return Err("custom parsing failed".to_string());
+    }
+    NaiveDateTime::parse_from_str(date, "%d-%b-%Y %T GMT").map_err(|e| e.to_string())
+}
+
+/// Parse an HTTP-date in any of its three forms, including asctime, which
+/// is in GMT like the others even though it does not say so.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::parse_http_date;
+///
+/// let result = parse_http_date("Sun Nov  6 08:49:37 1994");
+/// assert_eq!(result.unwrap().0.to_rfc3339(), "1994-11-06T08:49:37+00:00");
+/// ```
+pub fn parse_http_date(s: &str) -> Result<DateTimeFixedOffset, Error> {
+    read_http_date(normalize_unicode(s).trim(), true).map(DateTimeFixedOffset)
+}
+
+/// Parse the value of an HTTP `Expires` header or cookie attribute.
+///
+/// RFC 9110 asks caches to treat an invalid date, especially `0`, as a time in
+/// the past, so any value that is not an HTTP-date comes back as the unix epoch.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::parse_http_expires;
+///
+/// let expires = parse_http_expires("Wed, 07-Aug-2023 08:23:50 GMT");
+/// assert_eq!(expires.0.to_rfc3339(), "2023-08-07T08:23:50+00:00");
+///
+/// let expired = parse_http_expires("0");
+/// assert_eq!(expired.0.timestamp(), 0);
+/// ```
+pub fn parse_http_expires(s: &str) -> DateTimeFixedOffset {
+    DateTimeFixedOffset(
+        read_http_date(normalize_unicode(s).trim(), true)
+            .unwrap_or_else(|_| DateTime::UNIX_EPOCH.fixed_offset()),
+    )
+}
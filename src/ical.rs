@@ -0,0 +1,115 @@
+//! iCalendar (RFC 5545) DATE and DATE-TIME values, with their TZID parameter.
+
+use crate::{normalize_unicode, with_local_tz, DateTimeFixedOffset, Error};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+
+/// A parsed iCalendar DATE or DATE-TIME value.
+///
+/// RFC 5545 keeps floating times (no `Z` and no `TZID`) apart from times that
+/// are fixed to a zone: a floating `09:00` is 09:00 wherever the reader is.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ICalDateTime {
+    /// `VALUE=DATE`, eg: `20230807`
+    Date(NaiveDate),
+    /// a DATE-TIME without `Z` or `TZID`, eg: `20230807T082350`
+    Floating(NaiveDateTime),
+    /// a DATE-TIME in UTC or resolved against its `TZID`
+    Zoned(DateTimeFixedOffset),
+}
+
+/// Parse an iCalendar property line, its `parameters:value` part or just the value.
+///
+/// `TZID` is resolved against the IANA zone names. For local times that fall into
+/// a DST gap the offset from before the gap is used, and for repeated local times
+/// the first occurrence, as RFC 5545 section 3.3.5 asks.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::{parse_ical, ICalDateTime};
+///
+/// let start = parse_ical("DTSTART;TZID=Europe/Berlin:20230807T082350");
+/// if let Ok(ICalDateTime::Zoned(parsed)) = start {
+///     assert_eq!(parsed.0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+/// }
+///
+/// let floating = parse_ical("DTSTART:20230807T082350");
+/// assert!(matches!(floating, Ok(ICalDateTime::Floating(_))));
+/// ```
+pub fn parse_ical(s: &str) -> Result<ICalDateTime, Error> {
+    let s = normalize_unicode(s);
+    let s = s.trim();
+    let (params, value) = s.rsplit_once(':').unwrap_or(("", s));
+    let mut value_type = None;
+    let mut tzid = None;
+    for param in params.split(';') {
+        match param.split_once('=') {
+            Some((name, x)) if name.eq_ignore_ascii_case("VALUE") => value_type = Some(x),
+            Some((name, x)) if name.eq_ignore_ascii_case("TZID") => {
+                tzid = Some(x.trim_matches('"').trim_start_matches('/'))
+            }
+            _ => {}
+        }
+    }
+    match value_type {
+        Some(x) if x.eq_ignore_ascii_case("DATE") => parse_date(value),
+        Some(x) if x.eq_ignore_ascii_case("DATE-TIME") => parse_date_time(value, tzid),
+        Some(x) => Err(format!("unsupported VALUE type: {}", x)),
+        None if value.len() == 8 => parse_date(value),
+        None => parse_date_time(value, tzid),
+    }
+}
+
+/// eg: 20230807
+fn parse_date(s: &str) -> Result<ICalDateTime, Error> {
+    NaiveDate::parse_from_str(s, "%Y%m%d")
+        .map(ICalDateTime::Date)
+        .map_err(|e| e.to_string())
+}
+
+/// eg: 20230807T082350Z, 20230807T082350
+fn parse_date_time(s: &str, tzid: Option<&str>) -> Result<ICalDateTime, Error> {
+    if let Some(utc) = s.strip_suffix('Z') {
+        return NaiveDateTime::parse_from_str(utc, "%Y%m%dT%H%M%S")
+            .map(|x| ICalDateTime::Zoned(DateTimeFixedOffset(x.and_utc().fixed_offset())))
+            .map_err(|e| e.to_string());
+    }
+    let dt = NaiveDateTime::parse_from_str(s, "%Y%m%dT%H%M%S").map_err(|e| e.to_string())?;
+    match tzid {
+        None => Ok(ICalDateTime::Floating(dt)),
+        Some(tzid) => {
+            let tz = tzid
+                .parse::<Tz>()
+                .map_err(|_| format!("unknown TZID: {}", tzid))?;
+            let offset = tz
+                .offset_from_local_datetime(&dt)
+                .earliest()
+                .or_else(|| {
+                    tz.offset_from_local_datetime(&(dt - Duration::days(1)))
+                        .earliest()
+                })
+                .map(|x| x.fix())
+                .ok_or_else(|| format!("cannot resolve {} in {}", s, tzid))?;
+            dt.and_local_timezone(offset)
+                .single()
+                .map(|x| ICalDateTime::Zoned(DateTimeFixedOffset(x)))
+                .ok_or_else(|| format!("cannot resolve {} in {}", s, tzid))
+        }
+    }
+}
+
+/// iCalendar values for the general parser; dates and floating times get the
+/// local timezone like any other date/time without timezone information
+/// eg: DTSTART;TZID=Europe/Berlin:20230807T082350, 20230807T082350Z
+pub(crate) fn from_ical(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s.trim();
+    let (_, value) = s.rsplit_once(':').unwrap_or(("", s));
+    if !value.contains('T') && !s.contains("VALUE=DATE") {
+        return Err("custom parsing failed".to_string());
+    }
+    match parse_ical(s)? {
+        ICalDateTime::Date(x) => with_local_tz(x.and_time(Default::default())),
+        ICalDateTime::Floating(x) => with_local_tz(x),
+        ICalDateTime::Zoned(x) => Ok(x.0),
+    }
+}
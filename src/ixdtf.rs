@@ -0,0 +1,131 @@
+//! RFC 9557 Internet Extended Date/Time Format (IXDTF): RFC 3339 with a
+//! bracketed time zone and extension tags, as printed by Java's `ZonedDateTime`
+//! and the JavaScript Temporal API.
+
+use crate::{normalize_unicode, DateTimeFixedOffset, Error};
+use chrono::{DateTime, FixedOffset, Offset, TimeZone};
+use chrono_tz::Tz;
+
+/// A date/time with the time zone and calendar carried by its RFC 9557 suffix.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExtendedDateTime {
+    /// the instant, at the offset written in the timestamp
+    pub datetime: DateTimeFixedOffset,
+    /// the bracketed time zone, eg: `Europe/Berlin` or `+02:00`
+    pub time_zone: Option<String>,
+    /// the `u-ca` calendar tag, eg: `gregory`
+    pub calendar: Option<String>,
+    /// whether the offset disagrees with an elective bracketed zone, which the
+    /// RFC lets the application decide about
+    pub inconsistent: bool,
+}
+
+/// Parse an RFC 9557 timestamp such as `2023-08-07T08:23:50+02:00[Europe/Berlin]`.
+///
+/// The offset is checked against the bracketed zone. A mismatch, or an
+/// extension that cannot be honoured (an unknown key or a calendar other than
+/// `gregory`/`iso8601`), is only an error when the tag is marked critical
+/// with `!`; otherwise a mismatch sets `inconsistent` and other elective tags
+/// are ignored as the RFC allows. A `Z` timestamp says nothing about the local
+/// offset, so it is never inconsistent.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::parse_extended;
+///
+/// let parsed = parse_extended("2023-08-07T08:23:50+02:00[Europe/Berlin][u-ca=gregory]").unwrap();
+/// assert_eq!(parsed.time_zone.as_deref(), Some("Europe/Berlin"));
+/// assert_eq!(parsed.datetime.0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+///
+/// assert!(!parsed.inconsistent);
+///
+/// let parsed = parse_extended("2023-08-07T08:23:50+01:00[Europe/Berlin]").unwrap();
+/// assert!(parsed.inconsistent);
+/// assert!(parse_extended("2023-08-07T08:23:50+01:00[!Europe/Berlin]").is_err());
+/// ```
+pub fn parse_extended(s: &str) -> Result<ExtendedDateTime, Error> {
+    let s = normalize_unicode(s);
+    let s = s.trim();
+    let start = s
+        .find('[')
+        .ok_or_else(|| "missing bracketed suffix".to_string())?;
+    let (base, mut suffix) = s.split_at(start);
+    let datetime = DateTime::parse_from_rfc3339(base)
+        .or_else(|_| DateTime::parse_from_str(base, "%Y-%m-%dT%H:%M%:z"))
+        .or_else(|_| DateTime::parse_from_str(&base.replace('Z', "+00:00"), "%Y-%m-%dT%H:%M%:z"))
+        .map_err(|e| e.to_string())?;
+    let utc_only = base.ends_with(['Z', 'z']);
+
+    let mut time_zone = None;
+    let mut calendar = None;
+    let mut inconsistent = false;
+    let mut first = true;
+    while !suffix.is_empty() {
+        let end = suffix
+            .find(']')
+            .filter(|_| suffix.starts_with('['))
+            .ok_or_else(|| format!("invalid suffix: {}", suffix))?;
+        let tag = &suffix[1..end];
+        suffix = &suffix[end + 1..];
+        let (critical, tag) = match tag.strip_prefix('!') {
+            Some(tag) => (true, tag),
+            None => (false, tag),
+        };
+        match tag.split_once('=') {
+            None if first => {
+                inconsistent = !utc_only && !check_zone(&datetime, tag, critical)?;
+                time_zone = Some(tag.to_string());
+            }
+            None => return Err(format!("time zone must be the first tag: {}", tag)),
+            Some(("u-ca", value)) => {
+                if critical && !["gregory", "iso8601"].contains(&value) {
+                    return Err(format!("unsupported calendar: {}", value));
+                }
+                calendar.get_or_insert_with(|| value.to_string());
+            }
+            Some((key, _)) if critical => return Err(format!("unsupported extension: {}", key)),
+            Some(_) => {}
+        }
+        first = false;
+    }
+    Ok(ExtendedDateTime {
+        datetime: DateTimeFixedOffset(datetime),
+        time_zone,
+        calendar,
+        inconsistent,
+    })
+}
+
+/// Check the timestamp's offset against the bracketed zone, which is either an
+/// IANA name or a numeric offset, and tell whether they agree. Only a critical
+/// zone makes a mismatch, or a zone that is not known, fatal; an unknown
+/// elective zone cannot be checked and counts as agreeing.
+fn check_zone(datetime: &DateTime<FixedOffset>, zone: &str, critical: bool) -> Result<bool, Error> {
+    let expected = match zone.parse::<Tz>() {
+        Ok(tz) => tz.offset_from_utc_datetime(&datetime.naive_utc()).fix(),
+        Err(_) => {
+            match DateTime::parse_from_str(&format!("2000-01-01T00:00:00{}", zone), "%FT%T%:z") {
+                Ok(x) => *x.offset(),
+                Err(_) if critical => return Err(format!("unknown time zone: {}", zone)),
+                Err(_) => return Ok(true),
+            }
+        }
+    };
+    if expected == *datetime.offset() {
+        Ok(true)
+    } else if critical {
+        Err(format!(
+            "offset {} does not match the time zone {}",
+            datetime.offset(),
+            zone
+        ))
+    } else {
+        Ok(false)
+    }
+}
+
+/// Timestamps with an RFC 9557 suffix for the general parser
+/// eg: 2023-08-07T08:23:50+02:00[Europe/Berlin], 2023-08-07T08:23:50+02:00[u-ca=gregory]
+pub(crate) fn from_extended(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    parse_extended(s).map(|x| x.datetime.0)
+}
@@ -0,0 +1,148 @@
+//! Persian (Solar Hijri / Jalali) calendar dates, eg: `1402/05/16` or `۱۶ مرداد ۱۴۰۲`.
+
+use crate::{normalize_unicode, with_local_tz, DateTimeFixedOffset, Error};
+use chrono::{DateTime, Duration, FixedOffset, NaiveDate};
+
+/// Month names in Persian and transliterated, lowercase
+const MONTHS: [&[&str]; 12] = [
+    &["فروردین", "farvardin"],
+    &["اردیبهشت", "ordibehesht"],
+    &["خرداد", "khordad"],
+    &["تیر", "tir"],
+    &["مرداد", "امرداد", "mordad", "amordad"],
+    &["شهریور", "shahrivar"],
+    &["مهر", "mehr"],
+    &["آبان", "aban"],
+    &["آذر", "azar"],
+    &["دی", "dey", "dei"],
+    &["بهمن", "bahman"],
+    &["اسفند", "esfand"],
+];
+
+/// Jalali years where the 33-year leap cycle is interrupted, from the
+/// algorithm of Kazimierz Borkowski, which matches the astronomical calendar
+/// for the years it covers
+const BREAKS: [i32; 20] = [
+    -61, 9, 38, 199, 426, 686, 756, 818, 1111, 1181, 1210, 1635, 2060, 2097, 2192, 2262, 2324,
+    2394, 2456, 3178,
+];
+
+/// Parse a Jalali date: numeric `1402/05/16` or `1402-05-16` (year first),
+/// or with the month named, `16 Mordad 1402`. Persian and Arabic-Indic
+/// digits are accepted.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::parse_jalali;
+///
+/// let result = parse_jalali("1402/05/16");
+/// assert!(result.unwrap().0.to_rfc3339().starts_with("2023-08-07T00:00:00"));
+///
+/// let result = parse_jalali("۱۶ مرداد ۱۴۰۲");
+/// assert!(result.unwrap().0.to_rfc3339().starts_with("2023-08-07T00:00:00"));
+/// ```
+pub fn parse_jalali(s: &str) -> Result<DateTimeFixedOffset, Error> {
+    let s = normalize(s);
+    let (year, month, day) = match named_month(&s) {
+        Some(named) => named?,
+        None => {
+            let numbers = s
+                .split(['/', '-', '.'])
+                .map(|x| x.trim().parse::<i32>())
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| e.to_string())?;
+            match numbers[..] {
+                [year, month, day] => (year, month as u32, day as u32),
+                _ => return Err("custom parsing failed".to_string()),
+            }
+        }
+    };
+    to_gregorian(year, month, day)
+        .and_then(|x| with_local_tz(x.and_time(Default::default())))
+        .map(DateTimeFixedOffset)
+}
+
+/// Jalali dates with the month named, for the general parser; numeric dates
+/// look Gregorian and need `parse_jalali`
+/// eg: ۱۶ مرداد ۱۴۰۲, 16 Mordad 1402
+pub(crate) fn from_jalali(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let (year, month, day) =
+        named_month(&normalize(s)).ok_or_else(|| "custom parsing failed".to_string())??;
+    with_local_tz(to_gregorian(year, month, day)?.and_time(Default::default()))
+}
+
+/// Digits to ASCII, Arabic letters to their Persian forms, lowercase
+fn normalize(s: &str) -> String {
+    normalize_unicode(s.trim())
+        .replace('ي', "ی")
+        .replace('ك', "ک")
+        .to_lowercase()
+}
+
+/// `day month year` with a month name, or `None` without a month name
+fn named_month(s: &str) -> Option<Result<(i32, u32, u32), Error>> {
+    let tokens = s.split_whitespace().collect::<Vec<_>>();
+    let (day, month, year) = match tokens[..] {
+        [day, month, year] => (day, month, year),
+        _ => return None,
+    };
+    let month = MONTHS.iter().position(|x| x.contains(&month))? as u32 + 1;
+    Some(
+        day.parse::<u32>()
+            .and_then(|day| year.parse::<i32>().map(|year| (year, month, day)))
+            .map_err(|e| e.to_string()),
+    )
+}
+
+/// Whether the year has 366 days, and the day in March of its first day (Nowruz)
+fn jalali_year(year: i32) -> Result<(bool, u32), Error> {
+    if year < BREAKS[0] || year >= BREAKS[BREAKS.len() - 1] {
+        return Err(format!("{} is out of the supported Jalali years", year));
+    }
+    let gregorian = year + 621;
+    let mut leap_jalali = -14;
+    let mut previous = BREAKS[0];
+    let mut jump = 0;
+    for &next in &BREAKS[1..] {
+        jump = next - previous;
+        if year < next {
+            break;
+        }
+        leap_jalali += jump / 33 * 8 + jump % 33 / 4;
+        previous = next;
+    }
+    let mut n = year - previous;
+    leap_jalali += n / 33 * 8 + (n % 33 + 3) / 4;
+    if jump % 33 == 4 && jump - n == 4 {
+        leap_jalali += 1;
+    }
+    let leap_gregorian = gregorian / 4 - (gregorian / 100 + 1) * 3 / 4 - 150;
+    let march = 20 + leap_jalali - leap_gregorian;
+    if jump - n < 6 {
+        n = n - jump + (jump + 4) / 33 * 33;
+    }
+    let leap = ((n + 1) % 33 - 1) % 4;
+    Ok((leap == 0, march as u32))
+}
+
+/// Convert a Jalali date to the Gregorian calendar
+fn to_gregorian(year: i32, month: u32, day: u32) -> Result<NaiveDate, Error> {
+    let (leap, march) = jalali_year(year)?;
+    let length = match month {
+        1..=6 => 31,
+        7..=11 => 30,
+        12 if leap => 30,
+        12 => 29,
+        _ => 0,
+    };
+    if day < 1 || day > length {
+        return Err(format!(
+            "{}/{}/{} is not a valid Jalali date",
+            year, month, day
+        ));
+    }
+    let nowruz = NaiveDate::from_ymd_opt(year + 621, 3, march)
+        .ok_or_else(|| "date out of range".to_string())?;
+    let days = (month as i64 - 1) * 31 - (month as i64 / 7) * (month as i64 - 7) + day as i64 - 1;
+    Ok(nowruz + Duration::days(days))
+}
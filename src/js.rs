@@ -0,0 +1,103 @@
+//! Output of JavaScript `Date` string methods, as printed by V8, SpiderMonkey,
+//! JavaScriptCore and old JScript. `toUTCString()` is an HTTP IMF-fixdate and
+//! `toISOString()` is RFC 3339, both of which the main chain already handles.
+
+use crate::{tz_offset, with_local_tz, Error};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime};
+
+/// Long zone names printed in brackets by `toString()`, with their offset in minutes
+const ZONE_NAMES: &[(&str, i32)] = &[
+    ("Coordinated Universal Time", 0),
+    ("Greenwich Mean Time", 0),
+    ("Western European Standard Time", 0),
+    ("Western European Summer Time", 60),
+    ("British Summer Time", 60),
+    ("Irish Standard Time", 60),
+    ("Central European Standard Time", 60),
+    ("Central European Summer Time", 120),
+    ("Eastern European Standard Time", 120),
+    ("Eastern European Summer Time", 180),
+    ("Moscow Standard Time", 180),
+    ("India Standard Time", 330),
+    ("China Standard Time", 480),
+    ("Japan Standard Time", 540),
+    ("Korean Standard Time", 540),
+    ("Australian Eastern Standard Time", 600),
+    ("Australian Eastern Daylight Time", 660),
+    ("New Zealand Standard Time", 720),
+    ("New Zealand Daylight Time", 780),
+    ("Hawaii-Aleutian Standard Time", -600),
+    ("Alaska Standard Time", -540),
+    ("Alaska Daylight Time", -480),
+    ("Pacific Standard Time", -480),
+    ("Pacific Daylight Time", -420),
+    ("Mountain Standard Time", -420),
+    ("Mountain Daylight Time", -360),
+    ("Central Standard Time", -360),
+    ("Central Daylight Time", -300),
+    ("Eastern Standard Time", -300),
+    ("Eastern Daylight Time", -240),
+    ("Atlantic Standard Time", -240),
+    ("Atlantic Daylight Time", -180),
+];
+
+/// Try to parse the following types of JavaScript dates
+/// Mon Aug 07 2023 08:23:50 GMT+0200 (Central European Summer Time) (`toString()`)
+/// Mon Aug 07 2023 08:23:50 GMT+0200 (CEST)
+/// Mon Aug 7 08:23:50 UTC+0200 2023 (JScript `toString()`)
+/// 8/7/2023, 8:23:50 AM (`toLocaleString('en-US')`)
+/// 8/7/2023 (`toLocaleDateString('en-US')`)
+pub(crate) fn from_js_date(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+    let (dt, zone_name) = match s.strip_suffix(')').and_then(|x| x.rsplit_once(" (")) {
+        Some((dt, name)) => (dt, Some(name)),
+        None => (s.as_str(), None),
+    };
+    let parsed = DateTime::parse_from_str(dt, "%a %b %d %Y %T GMT%z")
+        .or_else(|_| DateTime::parse_from_str(dt, "%a %b %e %T UTC%z %Y"))
+        .map_err(|e| e.to_string());
+    match (parsed, zone_name) {
+        (Ok(parsed), Some(name)) => match zone_offset(name) {
+            Some(offset) if offset != *parsed.offset() => Err(format!(
+                "offset {} does not match the zone ({})",
+                parsed.offset(),
+                name
+            )),
+            _ => Ok(parsed),
+        },
+        (Ok(parsed), None) => Ok(parsed),
+        (Err(e), Some(_)) => Err(e),
+        // browsers print the year in full; short years are left to the later stages
+        (Err(_), None) if !has_full_year(dt) => Err("custom parsing failed".to_string()),
+        (Err(_), None) => NaiveDateTime::parse_from_str(dt, "%m/%d/%Y, %I:%M:%S %p")
+            .or_else(|_| {
+                NaiveDate::parse_from_str(dt, "%m/%d/%Y").map(|x| x.and_time(Default::default()))
+            })
+            .map_err(|e| e.to_string())
+            .and_then(with_local_tz),
+    }
+}
+
+/// Whether the `m/d/yyyy` date has a four-digit year
+fn has_full_year(s: &str) -> bool {
+    s.split([',', ' '])
+        .next()
+        .and_then(|x| x.rsplit('/').next())
+        .is_some_and(|x| x.len() == 4)
+}
+
+/// Offset of the bracketed zone name, when it is a known long name or abbreviation
+fn zone_offset(name: &str) -> Option<FixedOffset> {
+    ZONE_NAMES
+        .iter()
+        .find(|(zone, _)| zone.eq_ignore_ascii_case(name))
+        .and_then(|(_, minutes)| FixedOffset::east_opt(minutes * 60))
+        // single letters are military zones, which browsers never print
+        .or_else(|| {
+            if name.len() > 1 {
+                tz_offset(name)
+            } else {
+                None
+            }
+        })
+}
@@ -0,0 +1,153 @@
+//! Julian calendar dates, as found in archival and genealogical sources,
+//! eg: `1 March 1712 O.S.` or `10 Feb 1750/51`.
+
+use crate::{normalize_unicode, parse_from, with_local_tz, DateTimeFixedOffset, Error};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, Weekday};
+
+/// Days between the Julian day number and chrono's day count from 1 January 1 CE
+const JDN_CE: i64 = 1721425;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Style {
+    /// `O.S.`, the Julian calendar
+    Old,
+    /// `N.S.`, the Gregorian calendar
+    New,
+}
+
+/// Parse a date in the Julian calendar, unless it is marked `N.S.`, and convert
+/// it to the proleptic Gregorian calendar. A dual year such as `1750/51` gives
+/// the legal year, which began on 25 March, and the historical one: the later
+/// year is the one the date is in.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::parse_julian;
+///
+/// let result = parse_julian("4 October 1582");
+/// assert!(result.unwrap().0.to_rfc3339().starts_with("1582-10-14T00:00:00"));
+/// ```
+pub fn parse_julian(s: &str) -> Result<DateTimeFixedOffset, Error> {
+    let (rest, style) = strip_style(&normalize_unicode(s));
+    match style {
+        Some(Style::New) => parse_from(&rest),
+        _ => from_julian(&rest),
+    }
+    .map(DateTimeFixedOffset)
+}
+
+/// Dates that say which calendar they are in, for the general parser: Julian
+/// when marked `O.S.` or written with a dual year, Gregorian when marked `N.S.`
+/// eg: 1 March 1712 O.S., 10 Feb 1750/51, 12 March 1712 (N.S.)
+pub(crate) fn from_old_style(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let (rest, style) = strip_style(s);
+    match style {
+        Some(Style::Old) => from_julian(&rest),
+        Some(Style::New) => parse_from(&rest),
+        None if rest.split_whitespace().any(|x| dual_year(x).is_some()) => from_julian(&rest),
+        None => Err("custom parsing failed".to_string()),
+    }
+}
+
+/// Remove an `O.S.`/`N.S.` marker, with or without dots and brackets
+fn strip_style(s: &str) -> (String, Option<Style>) {
+    let mut style = None;
+    let rest = s
+        .split_whitespace()
+        .filter(|token| {
+            let marker = token.replace(['.', '(', ')', ','], "").to_lowercase();
+            match marker.as_str() {
+                "os" if style.is_none() => style = Some(Style::Old),
+                "ns" if style.is_none() => style = Some(Style::New),
+                _ => return true,
+            }
+            false
+        })
+        .collect::<Vec<_>>()
+        .join(" ");
+    (rest, style)
+}
+
+/// The later year of a dual year, eg: 1751 for `1750/51` or 1700 for `1699/00`
+fn dual_year(token: &str) -> Option<i32> {
+    let (first, second) = token.trim_end_matches(',').split_once('/')?;
+    if !(3..=4).contains(&first.len())
+        || second.is_empty()
+        || second.len() > first.len()
+        || !(first.to_string() + second)
+            .chars()
+            .all(|c| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let first = first.parse::<i32>().ok()?;
+    let scale = 10_i32.pow(second.len() as u32);
+    let mut second = first - first % scale + second.parse::<i32>().ok()?;
+    if second <= first {
+        second += scale;
+    }
+    Some(second).filter(|x| *x == first + 1)
+}
+
+/// Read the date with the general parser, with the year swapped for one with
+/// the same Julian leap year, then convert the Julian date to Gregorian
+fn from_julian(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let mut tokens = s.split_whitespace().map(String::from).collect::<Vec<_>>();
+    let weekday = match tokens
+        .first()
+        .map(|x| x.trim_end_matches(',').parse::<Weekday>())
+    {
+        Some(Ok(weekday)) => {
+            tokens.remove(0);
+            Some(weekday)
+        }
+        _ => None,
+    };
+    let year_token = tokens.iter().enumerate().rev().find_map(|(i, token)| {
+        let year = token.trim_end_matches(',');
+        match year.parse::<i32>() {
+            Ok(year) if (3..=4).contains(&token.trim_end_matches(',').len()) => Some((i, year)),
+            _ => dual_year(year).map(|year| (i, year)),
+        }
+    });
+    let (year, parsed) = match year_token {
+        Some((i, year)) => {
+            let comma = if tokens[i].ends_with(',') { "," } else { "" };
+            tokens[i] = format!("{}{}", 2000 + year.rem_euclid(4), comma);
+            (year, parse_from(&tokens.join(" "))?)
+        }
+        // numeric dates such as 1712-03-01 are read as they are
+        None => {
+            let parsed = parse_from(&tokens.join(" "))?;
+            (parsed.year(), parsed)
+        }
+    };
+    let date = julian_to_gregorian(year, parsed.month(), parsed.day())?;
+    if let Some(weekday) = weekday {
+        if date.weekday() != weekday {
+            return Err(format!("{} is not a {}", date, weekday));
+        }
+    }
+    let datetime = date.and_time(parsed.time());
+    // keep an offset that was written in the date, rather than the local one
+    if with_local_tz(parsed.naive_local())?.offset() == parsed.offset() {
+        with_local_tz(datetime)
+    } else {
+        datetime
+            .and_local_timezone(*parsed.offset())
+            .single()
+            .ok_or_else(|| "custom parsing failed".to_string())
+    }
+}
+
+/// Convert a date of the Julian calendar, through its Julian day number
+fn julian_to_gregorian(year: i32, month: u32, day: u32) -> Result<NaiveDate, Error> {
+    let a = (14 - month as i64) / 12;
+    let y = year as i64 + 4800 - a;
+    let m = month as i64 + 12 * a - 3;
+    let jdn = day as i64 + (153 * m + 2) / 5 + 365 * y + y / 4 - 32083;
+    i32::try_from(jdn - JDN_CE)
+        .ok()
+        .and_then(NaiveDate::from_num_days_from_ce_opt)
+        .ok_or_else(|| "date out of range".to_string())
+}
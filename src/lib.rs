@@ -8,9 +8,44 @@ use chrono::{
     TimeZone,
 };
 
+mod asn1;
+mod cjk;
+mod clf;
+mod dotnet;
+mod email;
+mod era;
+mod exif;
+mod git;
+mod hebrew;
+mod hijri;
+mod http;
+mod ical;
+mod ixdtf;
+mod jalali;
+mod js;
+mod julian;
+mod locale;
+mod ordinal;
+mod pdf;
+mod repr;
+mod roman;
+mod sql;
+mod syslog;
 #[cfg(test)]
 mod tests;
 
+pub use email::parse_email_date;
+pub use era::{parse_with_era, Era};
+pub use exif::{parse_exif, parse_xmp};
+pub use hijri::{parse_hijri, HijriCalendar, UmmAlQuraTable};
+pub use http::{parse_http_date, parse_http_expires};
+pub use ical::{parse_ical, ICalDateTime};
+pub use ixdtf::{parse_extended, ExtendedDateTime};
+pub use jalali::parse_jalali;
+pub use julian::parse_julian;
+pub use locale::{parse_localized, Locale};
+pub use sql::{parse_sql_timestamp, SqlTimestamp};
+
 type Error = String;
 
 /// DateTimeFixedOffset returns a str containing date time to a
@@ -28,7 +63,7 @@ type Error = String;
 ///     Err(e) => println!("Error: {}", e)
 /// }
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct DateTimeFixedOffset(pub DateTime<FixedOffset>);
 
 impl std::str::FromStr for DateTimeFixedOffset {
@@ -45,8 +80,33 @@ fn parse_from(date_time: &str) -> Result<DateTime<FixedOffset>, Error> {
     if date_time.is_empty() {
         Err("cannot be empty".to_string())
     } else {
-        let date_time = standardize_date(date_time);
-        from_unix_timestamp(&date_time)
+        let normalized = normalize_unicode(date_time);
+        let raw = normalized.trim();
+        let date_time = standardize_date(&normalized);
+        http::from_http_date(raw)
+            .or_else(|_| email::from_email_date(raw))
+            .or_else(|_| clf::from_common_log_format(raw))
+            .or_else(|_| git::from_git_date(raw))
+            .or_else(|_| exif::from_media_date(raw))
+            .or_else(|_| asn1::from_asn1_time(raw))
+            .or_else(|_| pdf::from_pdf_date(raw))
+            .or_else(|_| ical::from_ical(raw))
+            .or_else(|_| ixdtf::from_extended(raw))
+            .or_else(|_| repr::from_runtime_repr(raw))
+            .or_else(|_| dotnet::from_ms_json_date(raw))
+            .or_else(|_| era::from_japanese_era(raw))
+            .or_else(|_| era::from_era_year(raw))
+            .or_else(|_| hijri::from_hijri(raw))
+            .or_else(|_| jalali::from_jalali(raw))
+            .or_else(|_| hebrew::from_hebrew(raw))
+            .or_else(|_| julian::from_old_style(raw))
+            .or_else(|_| roman::from_roman_month(raw))
+            .or_else(|_| cjk::from_cjk_date(raw))
+            .or_else(|_| ordinal::from_ordinal_date(raw))
+            .or_else(|_| locale::from_localized(raw))
+            .or_else(|_| js::from_js_date(raw))
+            .or_else(|_| sql::from_sql_timestamp(raw))
+            .or_else(|_| from_unix_timestamp(&date_time))
             .or_else(|_| DateTime::parse_from_str(&date_time, "%+"))
             .or_else(|_| from_datetime_with_tz(&date_time))
             .or_else(|_| from_datetime_without_tz(&date_time))
@@ -57,6 +117,7 @@ fn parse_from(date_time: &str) -> Result<DateTime<FixedOffset>, Error> {
             .or_else(|_| try_dmmmy_hms_tz(&date_time))
             .or_else(|_| try_mmmddyyyy_hms_tz(&date_time))
             .or_else(|_| from_datetime_with_tz_before_year(&date_time))
+            .or_else(|_| syslog::from_syslog(raw))
             .or_else(|_| try_others(&date_time))
     }
 }
@@ -334,6 +395,38 @@ fn is_tz_alpha(s: &str) -> Option<(&str, &str)> {
     }
 }
 
+/// Resolve a timezone abbreviation known to RFC 2822, a common unambiguous
+/// European/Asia-Pacific one or a numeric offset like `+0200` to a fixed offset
+fn tz_offset(tz: &str) -> Option<FixedOffset> {
+    match tz {
+        "UTC" | "Z" | "WET" => FixedOffset::east_opt(0),
+        "CET" | "WEST" | "BST" => FixedOffset::east_opt(3600),
+        "CEST" | "EET" | "SAST" => FixedOffset::east_opt(2 * 3600),
+        "EEST" | "MSK" => FixedOffset::east_opt(3 * 3600),
+        "HKT" | "SGT" | "AWST" => FixedOffset::east_opt(8 * 3600),
+        "JST" | "KST" => FixedOffset::east_opt(9 * 3600),
+        "AEST" => FixedOffset::east_opt(10 * 3600),
+        "AEDT" => FixedOffset::east_opt(11 * 3600),
+        "NZST" => FixedOffset::east_opt(12 * 3600),
+        "NZDT" => FixedOffset::east_opt(13 * 3600),
+        "HST" => FixedOffset::west_opt(10 * 3600),
+        "AKST" => FixedOffset::west_opt(9 * 3600),
+        "AKDT" => FixedOffset::west_opt(8 * 3600),
+        _ => DateTime::parse_from_rfc2822(&format!("Thu, 01 Jan 1970 00:00:00 {}", tz))
+            .ok()
+            .map(|x| *x.offset()),
+    }
+}
+
+/// Attach the local timezone to a date/time that does not carry any timezone information
+fn with_local_tz(dt: NaiveDateTime) -> Result<DateTime<FixedOffset>, Error> {
+    Local
+        .from_local_datetime(&dt)
+        .earliest()
+        .map(|x| x.fixed_offset())
+        .ok_or_else(|| "date/time does not exist in the local timezone".to_string())
+}
+
 /// Convert the given date/time and timezone information into RFC 2822 format
 fn to_rfc2822(s: &str, tz: &str) -> Result<DateTime<FixedOffset>, Error> {
     NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
@@ -393,11 +486,47 @@ fn to_rfc2822(s: &str, tz: &str) -> Result<DateTime<FixedOffset>, Error> {
         .map_err(|e| e.to_string())
 }
 
+/// Code points of the digit zero in the Unicode scripts whose digits are folded to ASCII:
+/// Arabic-Indic, Extended Arabic-Indic (Persian), NKo, the Indic scripts from Devanagari
+/// to Malayalam, Thai, Lao, Tibetan, Myanmar, Khmer, Mongolian and full-width
+const DIGIT_ZEROS: [u32; 19] = [
+    0x0660, 0x06f0, 0x07c0, 0x0966, 0x09e6, 0x0a66, 0x0ae6, 0x0b66, 0x0be6, 0x0c66, 0x0ce6, 0x0d66,
+    0x0e50, 0x0ed0, 0x0f20, 0x1040, 0x17e0, 0x1810, 0xff10,
+];
+
+/// folds digits, spaces and punctuation typed on international keyboards or copied
+/// from formatted text to ASCII, leaving letters in other scripts alone
+/// eg: ２０２３-０８-０７ to 2023-08-07, 8:23\u{202f}AM to 8:23 AM, \u{2212}05:00 to -05:00
+pub(crate) fn normalize_unicode(s: &str) -> String {
+    s.chars()
+        .filter(|c| {
+            !matches!(
+                c,
+                '\u{200b}' | '\u{200e}' | '\u{200f}' | '\u{2060}' | '\u{feff}'
+            )
+        })
+        .map(|c| {
+            let code = c as u32;
+            if let Some(zero) = DIGIT_ZEROS.iter().find(|x| (**x..**x + 10).contains(&code)) {
+                return char::from(b'0' + (code - zero) as u8);
+            }
+            match c {
+                '\u{a0}' | '\u{2000}'..='\u{200a}' | '\u{202f}' | '\u{205f}' | '\u{3000}' => ' ',
+                '\u{2010}'..='\u{2015}' | '\u{2212}' | '\u{fe58}' | '\u{fe63}' => '-',
+                '\u{2044}' | '\u{2215}' => '/',
+                // the rest of the full-width forms of ASCII
+                '\u{ff01}'..='\u{ff5e}' => char::from_u32(code - 0xfee0).unwrap_or(c),
+                _ => c,
+            }
+        })
+        .collect()
+}
+
 /// converts date/time string from having '.' or '/' to '-'
 /// and remove extra characters like ',', ';'
 /// eg: 12/13/2000 to 12-13-2000 or 12/13/2000 12:12:12.14 to 12-13-2000 12:12:12.14
 fn standardize_date(s: &str) -> String {
-    if s.len() < 8 {
+    if s.chars().count() < 8 {
         s.to_string()
     } else {
         s.chars()
@@ -408,8 +537,8 @@ fn standardize_date(s: &str) -> String {
                 };
                 x
             })
+            .chain(s.chars().skip(8))
             .collect::<String>()
-            + &s[8..]
     }
     .replace(" UTC", " GMT")
     .replace(" UT", " GMT")
@@ -0,0 +1,393 @@
+//! Month and weekday names in languages other than English.
+
+use crate::{normalize_unicode, parse_from, with_local_tz, DateTimeFixedOffset, Error};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDate, NaiveDateTime, Weekday};
+
+/// Language of the month and weekday names in a date
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Locale {
+    /// detect the language from the month names
+    Auto,
+    English,
+    French,
+    German,
+    Spanish,
+    Italian,
+    Portuguese,
+    Dutch,
+    Polish,
+    Russian,
+}
+
+const LOCALES: &[Locale] = &[
+    Locale::French,
+    Locale::German,
+    Locale::Spanish,
+    Locale::Italian,
+    Locale::Portuguese,
+    Locale::Dutch,
+    Locale::Polish,
+    Locale::Russian,
+];
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+impl Locale {
+    /// Month names per month, full names (nominative and genitive) first,
+    /// followed by abbreviations which are only used when the locale is given
+    fn months(self) -> [(&'static [&'static str], &'static [&'static str]); 12] {
+        match self {
+            Locale::Auto | Locale::English => [(&[], &[]); 12],
+            Locale::French => [
+                (&["janvier"], &["janv"]),
+                (&["février", "fevrier"], &["févr", "fevr", "fév"]),
+                (&["mars"], &[]),
+                (&["avril"], &["avr"]),
+                (&["mai"], &[]),
+                (&["juin"], &[]),
+                (&["juillet"], &["juil"]),
+                (&["août", "aout"], &[]),
+                (&["septembre"], &["sept"]),
+                (&["octobre"], &["oct"]),
+                (&["novembre"], &["nov"]),
+                (&["décembre", "decembre"], &["déc", "dec"]),
+            ],
+            Locale::German => [
+                (&["januar", "jänner"], &["jan", "jän"]),
+                (&["februar"], &["feb", "febr"]),
+                (&["märz"], &["mär", "mrz"]),
+                (&["april"], &["apr"]),
+                (&["mai"], &[]),
+                (&["juni"], &["jun"]),
+                (&["juli"], &["jul"]),
+                (&["august"], &["aug"]),
+                (&["september"], &["sep", "sept"]),
+                (&["oktober"], &["okt"]),
+                (&["november"], &["nov"]),
+                (&["dezember"], &["dez"]),
+            ],
+            Locale::Spanish => [
+                (&["enero"], &["ene"]),
+                (&["febrero"], &["feb"]),
+                (&["marzo"], &["mar"]),
+                (&["abril"], &["abr"]),
+                (&["mayo"], &["may"]),
+                (&["junio"], &["jun"]),
+                (&["julio"], &["jul"]),
+                (&["agosto"], &["ago"]),
+                (&["septiembre", "setiembre"], &["sep", "sept", "set"]),
+                (&["octubre"], &["oct"]),
+                (&["noviembre"], &["nov"]),
+                (&["diciembre"], &["dic"]),
+            ],
+            Locale::Italian => [
+                (&["gennaio"], &["gen"]),
+                (&["febbraio"], &["feb"]),
+                (&["marzo"], &["mar"]),
+                (&["aprile"], &["apr"]),
+                (&["maggio"], &["mag"]),
+                (&["giugno"], &["giu"]),
+                (&["luglio"], &["lug"]),
+                (&["agosto"], &["ago"]),
+                (&["settembre"], &["set"]),
+                (&["ottobre"], &["ott"]),
+                (&["novembre"], &["nov"]),
+                (&["dicembre"], &["dic"]),
+            ],
+            Locale::Portuguese => [
+                (&["janeiro"], &["jan"]),
+                (&["fevereiro"], &["fev"]),
+                (&["março", "marco"], &["mar"]),
+                (&["abril"], &["abr"]),
+                (&["maio"], &["mai"]),
+                (&["junho"], &["jun"]),
+                (&["julho"], &["jul"]),
+                (&["agosto"], &["ago"]),
+                (&["setembro"], &["set"]),
+                (&["outubro"], &["out"]),
+                (&["novembro"], &["nov"]),
+                (&["dezembro"], &["dez"]),
+            ],
+            Locale::Dutch => [
+                (&["januari"], &["jan"]),
+                (&["februari"], &["feb"]),
+                (&["maart"], &["mrt"]),
+                (&["april"], &["apr"]),
+                (&["mei"], &[]),
+                (&["juni"], &["jun"]),
+                (&["juli"], &["jul"]),
+                (&["augustus"], &["aug"]),
+                (&["september"], &["sep", "sept"]),
+                (&["oktober"], &["okt"]),
+                (&["november"], &["nov"]),
+                (&["december"], &["dec"]),
+            ],
+            Locale::Polish => [
+                (&["styczeń", "stycznia"], &["sty"]),
+                (&["luty", "lutego"], &["lut"]),
+                (&["marzec", "marca"], &["mar"]),
+                (&["kwiecień", "kwietnia"], &["kwi"]),
+                (&["maj", "maja"], &[]),
+                (&["czerwiec", "czerwca"], &["cze"]),
+                (&["lipiec", "lipca"], &["lip"]),
+                (&["sierpień", "sierpnia"], &["sie"]),
+                (&["wrzesień", "września"], &["wrz"]),
+                (&["październik", "października"], &["paź"]),
+                (&["listopad", "listopada"], &["lis"]),
+                (&["grudzień", "grudnia"], &["gru"]),
+            ],
+            Locale::Russian => [
+                (&["январь", "января"], &["янв"]),
+                (&["февраль", "февраля"], &["фев", "февр"]),
+                (&["март", "марта"], &["мар"]),
+                (&["апрель", "апреля"], &["апр"]),
+                (&["май", "мая"], &[]),
+                (&["июнь", "июня"], &["июн"]),
+                (&["июль", "июля"], &["июл"]),
+                (&["август", "августа"], &["авг"]),
+                (&["сентябрь", "сентября"], &["сен", "сент"]),
+                (&["октябрь", "октября"], &["окт"]),
+                (&["ноябрь", "ноября"], &["ноя", "нояб"]),
+                (&["декабрь", "декабря"], &["дек"]),
+            ],
+        }
+    }
+
+    /// Weekday names from Monday to Sunday
+    fn weekdays(self) -> [&'static [&'static str]; 7] {
+        match self {
+            Locale::Auto | Locale::English => [&[]; 7],
+            Locale::French => [
+                &["lundi"],
+                &["mardi"],
+                &["mercredi"],
+                &["jeudi"],
+                &["vendredi"],
+                &["samedi"],
+                &["dimanche"],
+            ],
+            Locale::German => [
+                &["montag"],
+                &["dienstag"],
+                &["mittwoch"],
+                &["donnerstag"],
+                &["freitag"],
+                &["samstag", "sonnabend"],
+                &["sonntag"],
+            ],
+            Locale::Spanish => [
+                &["lunes"],
+                &["martes"],
+                &["miércoles", "miercoles"],
+                &["jueves"],
+                &["viernes"],
+                &["sábado", "sabado"],
+                &["domingo"],
+            ],
+            Locale::Italian => [
+                &["lunedì", "lunedi"],
+                &["martedì", "martedi"],
+                &["mercoledì", "mercoledi"],
+                &["giovedì", "giovedi"],
+                &["venerdì", "venerdi"],
+                &["sabato"],
+                &["domenica"],
+            ],
+            Locale::Portuguese => [
+                &["segunda-feira", "segunda"],
+                &["terça-feira", "terça"],
+                &["quarta-feira", "quarta"],
+                &["quinta-feira", "quinta"],
+                &["sexta-feira", "sexta"],
+                &["sábado", "sabado"],
+                &["domingo"],
+            ],
+            Locale::Dutch => [
+                &["maandag"],
+                &["dinsdag"],
+                &["woensdag"],
+                &["donderdag"],
+                &["vrijdag"],
+                &["zaterdag"],
+                &["zondag"],
+            ],
+            Locale::Polish => [
+                &["poniedziałek"],
+                &["wtorek"],
+                &["środa"],
+                &["czwartek"],
+                &["piątek"],
+                &["sobota"],
+                &["niedziela"],
+            ],
+            Locale::Russian => [
+                &["понедельник"],
+                &["вторник"],
+                &["среда"],
+                &["четверг"],
+                &["пятница"],
+                &["суббота"],
+                &["воскресенье"],
+            ],
+        }
+    }
+
+    /// Connecting words that carry no date information,
+    /// eg: `7 de agosto de 2023`, `7 августа 2023 г.`, `à 08h23`
+    fn filler_words(self) -> &'static [&'static str] {
+        match self {
+            Locale::Auto | Locale::English => &[],
+            Locale::French => &["le", "à", "a"],
+            Locale::German => &["den", "am", "um", "uhr"],
+            Locale::Spanish => &["de", "del", "a", "las", "la"],
+            Locale::Italian => &["il", "alle", "ore"],
+            Locale::Portuguese => &["de", "às", "as"],
+            Locale::Dutch => &["om"],
+            Locale::Polish => &["r", "roku", "o", "godz"],
+            Locale::Russian => &["г", "года", "в"],
+        }
+    }
+
+    /// The month for a (lowercase) name, with abbreviations only when asked for
+    fn month(self, name: &str, abbreviations: bool) -> Option<usize> {
+        self.months().iter().position(|(full, short)| {
+            full.contains(&name) || (abbreviations && short.contains(&name))
+        })
+    }
+
+    fn weekday(self, name: &str) -> Option<Weekday> {
+        self.weekdays()
+            .iter()
+            .position(|names| names.contains(&name))
+            .and_then(|x| Weekday::try_from(x as u8).ok())
+    }
+}
+
+/// Parse a date whose month and weekday names are in the given language.
+/// With `Locale::Auto` the language is picked by finding the month name in the
+/// dictionaries; abbreviated month names need the locale to be given.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::{parse_localized, Locale};
+///
+/// let result = parse_localized("Montag, 7. August 2023", Locale::German);
+/// assert!(result.unwrap().0.to_rfc3339().starts_with("2023-08-07T00:00:00"));
+///
+/// let result = parse_localized("7 de agosto de 2023", Locale::Auto);
+/// assert!(result.unwrap().0.to_rfc3339().starts_with("2023-08-07T00:00:00"));
+/// ```
+pub fn parse_localized(s: &str, locale: Locale) -> Result<DateTimeFixedOffset, Error> {
+    let s = &normalize_unicode(s);
+    match locale {
+        Locale::English => parse_from(s),
+        Locale::Auto => from_localized(s).or_else(|_| parse_from(s)),
+        locale => translate(s, locale, true)
+            .and_then(|(english, weekday)| from_english(&english, weekday)),
+    }
+    .map(DateTimeFixedOffset)
+}
+
+/// Dates with month names in one of the supported languages, for the general parser
+/// eg: 7 août 2023, Montag, 7. August 2023, 7 de agosto de 2023, 7 августа 2023 г.
+pub(crate) fn from_localized(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    LOCALES
+        .iter()
+        .find_map(|locale| {
+            translate(s, *locale, false)
+                .and_then(|(english, weekday)| from_english(&english, weekday))
+                .ok()
+        })
+        .ok_or_else(|| "custom parsing failed".to_string())
+}
+
+/// Rewrite the date with English month names, dropping the weekday and the
+/// connecting words, so that the English patterns apply. Input that is
+/// already English comes back unchanged and is left to the other stages.
+fn translate(
+    s: &str,
+    locale: Locale,
+    abbreviations: bool,
+) -> Result<(String, Option<Weekday>), Error> {
+    let mut words = Vec::new();
+    let mut weekday = None;
+    let mut has_month = false;
+    for token in s.split_whitespace() {
+        let token = token.trim_end_matches([',', '.']).to_lowercase();
+        if token.is_empty() || locale.filler_words().contains(&token.as_str()) {
+            continue;
+        }
+        if let Some(month) = locale.month(&token, abbreviations) {
+            words.push(MONTHS[month].to_string());
+            has_month = true;
+        } else if let Some(day) = locale.weekday(&token) {
+            weekday = Some(day);
+        } else {
+            words.push(french_time(&token));
+        }
+    }
+    let english = words.join(" ");
+    if !has_month {
+        Err(format!("no {:?} month name found", locale))
+    } else if weekday.is_none() && english == s.split_whitespace().collect::<Vec<_>>().join(" ") {
+        Err("custom parsing failed".to_string())
+    } else {
+        Ok((english, weekday))
+    }
+}
+
+/// French writes times as `8h23` or `8h`
+fn french_time(token: &str) -> String {
+    match token.split_once('h') {
+        Some((hour, minute))
+            if (1..=2).contains(&hour.len())
+                && [0, 2].contains(&minute.len())
+                && (hour.to_string() + minute)
+                    .chars()
+                    .all(|c| c.is_ascii_digit()) =>
+        {
+            format!("{}:{:0>2}", hour, minute)
+        }
+        _ => token.to_string(),
+    }
+}
+
+/// Parse the translated date, checking the weekday when there was one
+/// eg: 7 August 2023 08:23, August 7 2023
+fn from_english(s: &str, weekday: Option<Weekday>) -> Result<DateTime<FixedOffset>, Error> {
+    let parsed = ["%d %B %Y", "%B %d %Y", "%Y %B %d"]
+        .iter()
+        .find_map(|date| {
+            ["%H:%M:%S", "%H:%M"]
+                .iter()
+                .find_map(|time| {
+                    NaiveDateTime::parse_from_str(s, &format!("{} {}", date, time)).ok()
+                })
+                .or_else(|| {
+                    NaiveDate::parse_from_str(s, date)
+                        .ok()
+                        .map(|x| x.and_time(Default::default()))
+                })
+        })
+        .map(with_local_tz)
+        .unwrap_or_else(|| parse_from(s))?;
+    match weekday {
+        Some(weekday) if parsed.weekday() != weekday => {
+            Err(format!("{} is not a {}", parsed.date_naive(), weekday))
+        }
+        _ => Ok(parsed),
+    }
+}
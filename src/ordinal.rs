@@ -0,0 +1,131 @@
+//! English dates written with ordinal days and connecting words, eg: `the 1st of May`.
+
+use crate::{parse_from, Error};
+use chrono::{DateTime, Datelike, FixedOffset, Local, Month, Weekday};
+
+/// Try to parse the following types of human-written dates
+/// 7th August 2023
+/// August 7th, 2023
+/// the 1st of May
+/// Monday, 2nd October
+/// 3rd Jan 2024 at 5pm
+/// August 7th, 2023 at 5:30pm
+///
+/// The ordinal suffix has to match the number (`1st`, `2nd`, `3rd`, `11th`, `22nd`).
+/// The day name is checked against the date; without a year, the nearest year
+/// where the day name matches is used.
+pub(crate) fn from_ordinal_date(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let mut words = Vec::new();
+    let mut has_ordinal = false;
+    for token in s.split_whitespace() {
+        let token = token.trim_end_matches(',');
+        if ["the", "of", "at", "on"].contains(&token.to_lowercase().as_str()) {
+            continue;
+        }
+        match ordinal_day(token) {
+            Some(day) => {
+                words.push(day?.to_string());
+                has_ordinal = true;
+            }
+            None => words.push(hour_with_minutes(token)),
+        }
+    }
+    // a day on its own would be read as a unix timestamp
+    let has_month = words
+        .iter()
+        .any(|x| x.trim_end_matches('.').parse::<Month>().is_ok());
+    if !has_ordinal || !has_month {
+        return Err("custom parsing failed".to_string());
+    }
+    let weekday = match words.first().map(|x| x.parse::<Weekday>()) {
+        Some(Ok(weekday)) => {
+            words.remove(0);
+            Some(weekday)
+        }
+        _ => None,
+    };
+    // put the day first, as only day-first dates are also read with a 12-hour
+    // time: `August 7th, 2023 at 5:30pm`
+    if words.len() >= 2
+        && words[0].trim_end_matches('.').parse::<Month>().is_ok()
+        && words[1].chars().all(|c| c.is_ascii_digit())
+    {
+        words.swap(0, 1);
+    }
+    // hours on their own, before a separate am/pm: `5 pm`
+    for i in 1..words.len() {
+        if ["am", "pm"].contains(&words[i].to_lowercase().as_str())
+            && words[i - 1].chars().all(|c| c.is_ascii_digit())
+        {
+            words[i - 1] += ":00";
+        }
+    }
+    let has_year = words
+        .iter()
+        .take(3)
+        .any(|x| x.len() == 4 && x.parse::<i32>().is_ok());
+    match weekday {
+        None => parse_from(&words.join(" ")),
+        Some(weekday) if has_year => parse_from(&words.join(" ")).and_then(|x| {
+            if x.weekday() == weekday {
+                Ok(x)
+            } else {
+                Err(format!("{} is not a {}", x.date_naive(), weekday))
+            }
+        }),
+        Some(weekday) => {
+            let this_year = Local::now().year();
+            (0..=14)
+                .flat_map(|n| [this_year + n, this_year - n])
+                .find_map(|year| {
+                    let mut words = words.clone();
+                    words.insert(2.min(words.len()), year.to_string());
+                    parse_from(&words.join(" "))
+                        .ok()
+                        .filter(|x| x.weekday() == weekday)
+                })
+                .ok_or_else(|| format!("no year near {} matches {}", this_year, weekday))
+        }
+    }
+}
+
+/// The day of an ordinal like `21st`, or `None` when the token is no ordinal.
+/// A suffix that does not fit the number (eg: `2th`) is an error.
+fn ordinal_day(token: &str) -> Option<Result<u32, Error>> {
+    let lower = token.to_lowercase();
+    let split = lower.find(|c: char| !c.is_ascii_digit())?;
+    let (day, suffix) = lower.split_at(split);
+    if day.is_empty() || day.len() > 2 || !["st", "nd", "rd", "th"].contains(&suffix) {
+        return None;
+    }
+    let day = day.parse::<u32>().ok()?;
+    let expected = match (day % 100, day % 10) {
+        (11..=13, _) => "th",
+        (_, 1) => "st",
+        (_, 2) => "nd",
+        (_, 3) => "rd",
+        _ => "th",
+    };
+    if suffix == expected {
+        Some(Ok(day))
+    } else {
+        Some(Err(format!(
+            "{} should be written {}{}",
+            token, day, expected
+        )))
+    }
+}
+
+/// Spell out the minutes of an hour-only time, eg: `5pm` to `5:00pm`
+fn hour_with_minutes(token: &str) -> String {
+    let lower = token.to_lowercase();
+    match lower
+        .strip_suffix("am")
+        .or_else(|| lower.strip_suffix("pm"))
+    {
+        Some(hour) if !hour.is_empty() && hour.len() <= 2 && hour.parse::<u32>().is_ok() => {
+            format!("{}:00{}", hour, &lower[hour.len()..])
+        }
+        _ => token.to_string(),
+    }
+}
@@ -0,0 +1,68 @@
+//! PDF date strings (PDF 1.7 / ISO 32000-1 section 7.9.4).
+
+use crate::{with_local_tz, Error};
+use chrono::{DateTime, FixedOffset, NaiveDate};
+
+/// Try to parse the following types of PDF dates, every field after the year is optional
+/// D:20230807082350+02'00'
+/// D:20230807082350Z
+/// D:202308
+/// D:2023
+pub(crate) fn from_pdf_date(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s
+        .trim()
+        .strip_prefix("D:")
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    let split = s.find(['+', '-', 'Z']).unwrap_or(s.len());
+    let (digits, tz) = s.split_at(split);
+    if digits.len() < 4
+        || digits.len() > 14
+        || digits.len() % 2 != 0
+        || !digits.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err("custom parsing failed".to_string());
+    }
+    let field = |i: usize, default: u32| {
+        digits
+            .get(i..i + 2)
+            .map_or(Some(default), |x| x.parse::<u32>().ok())
+    };
+    let dt = digits[..4]
+        .parse::<i32>()
+        .ok()
+        .and_then(|year| NaiveDate::from_ymd_opt(year, field(4, 1)?, field(6, 1)?))
+        .and_then(|date| date.and_hms_opt(field(8, 0)?, field(10, 0)?, field(12, 0)?))
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    if tz.is_empty() {
+        return with_local_tz(dt);
+    }
+    pdf_offset(tz)
+        .and_then(|offset| dt.and_local_timezone(offset).single())
+        .ok_or_else(|| "custom parsing failed".to_string())
+}
+
+/// PDF offsets separate hours and minutes with apostrophes, and both the
+/// trailing apostrophe and the minutes may be left out
+/// eg: +02'00', +02'00, -05, Z, Z00'00'
+fn pdf_offset(tz: &str) -> Option<FixedOffset> {
+    let (sign, rest) = tz.split_at(1);
+    let mut parts = rest.trim_end_matches('\'').split('\'');
+    let hours = match parts.next()? {
+        "" if sign == "Z" => 0,
+        x if x.len() == 2 => x.parse::<i32>().ok()?,
+        _ => return None,
+    };
+    let minutes = match parts.next() {
+        None => 0,
+        Some(x) if x.len() == 2 => x.parse::<i32>().ok()?,
+        _ => return None,
+    };
+    if parts.next().is_some() || hours > 23 || minutes > 59 {
+        return None;
+    }
+    let secs = (hours * 60 + minutes) * 60;
+    match sign {
+        "+" | "Z" => FixedOffset::east_opt(secs),
+        _ => FixedOffset::west_opt(secs),
+    }
+}
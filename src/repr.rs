@@ -0,0 +1,128 @@
+//! Date/time values as printed by language runtimes in logs and debuggers.
+
+use crate::{tz_offset, with_local_tz, Error};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, Offset, TimeZone};
+use chrono_tz::Tz;
+
+/// Try to parse the following types of runtime representations
+/// datetime.datetime(2023, 8, 7, 8, 23, 50, tzinfo=datetime.timezone.utc) (Python `repr()`)
+/// 2023-08-07 08:23:50.123456789 +0200 CEST m=+0.000012 (Go `time.Time.String()`)
+/// Mon Aug 07 08:23:50 CEST 2023 (Java `Date.toString()`)
+/// 2023-08-07 08:23:50.123 +0200 (Ruby `Time#inspect`)
+pub(crate) fn from_runtime_repr(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s.trim();
+    if s.starts_with("datetime.") {
+        return from_python(s);
+    }
+    let mut tokens = s.split_whitespace().collect::<Vec<_>>();
+    // Go ends with the monotonic clock reading
+    if tokens.last().is_some_and(|x| x.starts_with("m=")) {
+        tokens.pop();
+    }
+    match tokens[..] {
+        // Go prints the zone abbreviation after the offset, which adds nothing
+        [date, time, offset] | [date, time, offset, _]
+            if date.contains('-') && zone_name(&tokens) =>
+        {
+            DateTime::parse_from_str(&format!("{} {} {}", date, time, offset), "%F %T%.f %z")
+                .map_err(|e| e.to_string())
+        }
+        [weekday, month, day, time, zone, year] => {
+            let offset = tz_offset(zone).ok_or_else(|| format!("unknown zone: {}", zone))?;
+            NaiveDateTime::parse_from_str(
+                &format!("{} {} {} {} {}", weekday, month, day, year, time),
+                "%a %b %d %Y %T",
+            )
+            .map_err(|e| e.to_string())?
+            .and_local_timezone(offset)
+            .single()
+            .ok_or_else(|| "custom parsing failed".to_string())
+        }
+        _ => Err("custom parsing failed".to_string()),
+    }
+}
+
+/// Whether the token after the offset, if any, is a zone abbreviation, which
+/// Go writes as letters or, for zones without one, as a short offset (eg: `-03`)
+fn zone_name(tokens: &[&str]) -> bool {
+    match tokens.get(3) {
+        Some(zone) => {
+            zone.chars().all(|c| c.is_ascii_alphabetic())
+                || zone
+                    .strip_prefix(['+', '-'])
+                    .is_some_and(|x| !x.is_empty() && x.chars().all(|c| c.is_ascii_digit()))
+        }
+        None => true,
+    }
+}
+
+/// Python's `repr()` of `datetime.datetime` and `datetime.date`
+/// eg: datetime.datetime(2023, 8, 7, 8, 23, 50, 123000, tzinfo=datetime.timezone.utc)
+/// datetime.datetime(2023, 8, 7, 8, 23, tzinfo=zoneinfo.ZoneInfo(key='Europe/Berlin'))
+fn from_python(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let (kind, args) = s
+        .strip_suffix(')')
+        .and_then(|x| x.split_once('('))
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    let (fields, tzinfo) = match args.split_once("tzinfo=") {
+        Some((fields, tzinfo)) => (fields, Some(tzinfo.trim())),
+        None => (args, None),
+    };
+    let fields = fields
+        .split(',')
+        .map(str::trim)
+        .filter(|x| !x.is_empty())
+        .map(str::parse::<u32>)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    let field = |i: usize| fields.get(i).copied().unwrap_or(0);
+    let dt = match (kind, fields.len()) {
+        ("datetime.date", 3) | ("datetime.datetime", 3..=7) => {
+            NaiveDate::from_ymd_opt(field(0) as i32, field(1), field(2))
+                .and_then(|x| x.and_hms_micro_opt(field(3), field(4), field(5), field(6)))
+        }
+        _ => None,
+    }
+    .ok_or_else(|| "custom parsing failed".to_string())?;
+    let tzinfo = match tzinfo {
+        Some(tzinfo) => tzinfo,
+        None => return with_local_tz(dt),
+    };
+    let offset = if tzinfo == "datetime.timezone.utc" {
+        FixedOffset::east_opt(0)
+    } else if let Some(key) = tzinfo
+        .strip_prefix("zoneinfo.ZoneInfo(key='")
+        .and_then(|x| x.strip_suffix("')"))
+    {
+        let tz = key
+            .parse::<Tz>()
+            .map_err(|_| format!("unknown zone: {}", key))?;
+        tz.offset_from_local_datetime(&dt)
+            .earliest()
+            .map(|x| x.fix())
+    } else if let Some(delta) = tzinfo
+        .strip_prefix("datetime.timezone(datetime.timedelta(")
+        .and_then(|x| x.strip_suffix("))"))
+    {
+        timedelta_seconds(delta).and_then(FixedOffset::east_opt)
+    } else {
+        None
+    };
+    offset
+        .and_then(|x| dt.and_local_timezone(x).single())
+        .ok_or_else(|| format!("unsupported tzinfo: {}", tzinfo))
+}
+
+/// The keyword arguments of a `datetime.timedelta` repr, in seconds
+/// eg: seconds=7200, days=-1, seconds=68400
+fn timedelta_seconds(s: &str) -> Option<i32> {
+    s.split(',').try_fold(0, |total, arg| {
+        let (key, value) = arg.trim().split_once('=')?;
+        let value = value.parse::<i32>().ok()?;
+        match key {
+            "days" => Some(total + value * 86400),
+            "seconds" => Some(total + value),
+            _ => None,
+        }
+    })
+}
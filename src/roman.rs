@@ -0,0 +1,73 @@
+//! Dates with the month in Roman numerals, common in Central Europe, eg: `7.VIII.2023`.
+
+use crate::{with_local_tz, Error};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveTime};
+
+const NUMERALS: [&str; 12] = [
+    "I", "II", "III", "IV", "V", "VI", "VII", "VIII", "IX", "X", "XI", "XII",
+];
+
+/// Try to parse the following types of dates with Roman numeral months
+/// 7.VIII.2023
+/// 7 VIII 2023
+/// 7. VIII. 2023 08:23
+/// 2023-VIII-07
+/// VIII 7 2023
+///
+/// The year has to be written in full; the remaining number is the day,
+/// wherever the month is.
+pub(crate) fn from_roman_month(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let mut time = None;
+    let mut month = None;
+    let mut numbers = Vec::new();
+    for token in s.split_whitespace() {
+        if token.contains(':') && time.is_none() {
+            time = Some(
+                NaiveTime::parse_from_str(token, "%H:%M:%S")
+                    .or_else(|_| NaiveTime::parse_from_str(token, "%H:%M"))
+                    .map_err(|e| e.to_string())?,
+            );
+            continue;
+        }
+        let attached = token.trim_end_matches(['.', ',']).contains(['.', '-', '/']);
+        for part in token.split(['.', '-', '/', ',']).filter(|x| !x.is_empty()) {
+            if let Some(x) = roman_month(part, attached) {
+                if month.replace(x).is_some() {
+                    return Err("custom parsing failed".to_string());
+                }
+            } else if part.chars().all(|c| c.is_ascii_digit()) && part.len() <= 4 {
+                numbers.push(part);
+            } else {
+                return Err("custom parsing failed".to_string());
+            }
+        }
+    }
+    let month = month.ok_or_else(|| "custom parsing failed".to_string())?;
+    let (year, day) = match numbers[..] {
+        [a, b] if a.len() >= 3 && b.len() <= 2 => (a, b),
+        [a, b] if b.len() >= 3 && a.len() <= 2 => (b, a),
+        _ => return Err("custom parsing failed".to_string()),
+    };
+    let year = year.parse::<i32>().map_err(|e| e.to_string())?;
+    let day = day.parse::<u32>().map_err(|e| e.to_string())?;
+    NaiveDate::from_ymd_opt(year, month, day)
+        .ok_or_else(|| format!("invalid date: {}", s))
+        .and_then(|x| with_local_tz(x.and_time(time.unwrap_or_default())))
+}
+
+/// The month of a Roman numeral, in upper or lower case. A single lowercase
+/// letter is too likely to be a word, so it has to be joined to the rest of
+/// the date, eg: `7.x.2023`.
+fn roman_month(s: &str, attached: bool) -> Option<u32> {
+    if s != s.to_uppercase() && s != s.to_lowercase() {
+        return None;
+    }
+    if s.len() == 1 && s == s.to_lowercase() && !attached {
+        return None;
+    }
+    let upper = s.to_uppercase();
+    NUMERALS
+        .iter()
+        .position(|x| *x == upper)
+        .map(|x| x as u32 + 1)
+}
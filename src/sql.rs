@@ -0,0 +1,120 @@
+//! Timestamp literals as exported by databases (PostgreSQL, Oracle, MySQL,
+//! SQL Server and ANSI SQL typed literals).
+
+use crate::{normalize_unicode, parse_from, with_local_tz, DateTimeFixedOffset, Error};
+use chrono::{DateTime, Datelike, FixedOffset, NaiveDateTime, Utc};
+
+/// A database timestamp, including the special values some databases store
+/// in place of a real date.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SqlTimestamp {
+    /// an actual date/time
+    Value(DateTimeFixedOffset),
+    /// PostgreSQL `infinity`, later than every other timestamp
+    Infinity,
+    /// PostgreSQL `-infinity`, earlier than every other timestamp
+    NegativeInfinity,
+    /// MySQL zero date `0000-00-00 00:00:00`, used for "no date"
+    ZeroDate,
+}
+
+/// Parse a timestamp as written by a database, mapping PostgreSQL infinities
+/// and MySQL zero dates to their own variants instead of failing.
+///
+/// ## Example usage:
+/// ```
+/// use datetime_parse::{parse_sql_timestamp, SqlTimestamp};
+///
+/// assert_eq!(parse_sql_timestamp("infinity"), Ok(SqlTimestamp::Infinity));
+/// assert_eq!(parse_sql_timestamp("0000-00-00 00:00:00"), Ok(SqlTimestamp::ZeroDate));
+///
+/// let oracle = parse_sql_timestamp("07-AUG-23 08.23.50.000000 AM +02:00");
+/// assert!(matches!(oracle, Ok(SqlTimestamp::Value(_))));
+/// ```
+pub fn parse_sql_timestamp(s: &str) -> Result<SqlTimestamp, Error> {
+    let s = normalize_unicode(s);
+    let s = s.trim();
+    match s.to_ascii_lowercase().as_str() {
+        "infinity" | "+infinity" => return Ok(SqlTimestamp::Infinity),
+        "-infinity" => return Ok(SqlTimestamp::NegativeInfinity),
+        _ if is_zero_date(s) => return Ok(SqlTimestamp::ZeroDate),
+        _ => {}
+    }
+    from_sql_timestamp(s)
+        .or_else(|_| parse_from(s))
+        .map(|x| SqlTimestamp::Value(DateTimeFixedOffset(x)))
+}
+
+/// MySQL's zero date, with or without a time and fractional seconds
+/// eg: 0000-00-00, 0000-00-00 00:00:00, 0000-00-00 00:00:00.000000
+fn is_zero_date(s: &str) -> bool {
+    let (datetime, fraction) = s.split_once('.').unwrap_or((s, ""));
+    ["0000-00-00", "0000-00-00 00:00:00"].contains(&datetime)
+        && (fraction.is_empty() || datetime.len() > 10)
+        && fraction.chars().all(|c| c == '0')
+}
+
+/// Try to parse the following types of database timestamps
+/// 2023-08-07 08:23:50.123456+02 (PostgreSQL)
+/// 2023-08-07 08:23:50.1234567 +02:00 (SQL Server `datetimeoffset`)
+/// 2023-08-07 08:23:50.1234567 (SQL Server `datetime2`)
+/// 07-AUG-23 08.23.50.000000 AM +02:00 (Oracle)
+/// TIMESTAMP '2023-08-07 08:23:50' (ANSI SQL literal)
+pub(crate) fn from_sql_timestamp(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    if let Some(inner) = strip_typed_literal(s) {
+        return parse_from(inner);
+    }
+    let s = s.split_whitespace().collect::<Vec<_>>().join(" ");
+    DateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S%.f%#z")
+        .or_else(|_| DateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S%.f %:z"))
+        .map_err(|e| e.to_string())
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(&s, "%Y-%m-%d %H:%M:%S%.f")
+                .map_err(|e| e.to_string())
+                .and_then(with_local_tz)
+        })
+        .or_else(|_| from_oracle(&s))
+}
+
+/// Oracle's default `DD-MON-RR HH.MI.SSXFF AM TZR` format, with or without the zone
+/// eg: 07-AUG-23 08.23.50.000000 AM +02:00, 07-AUG-23 08.23.50.000000 AM
+fn from_oracle(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let (date, rest) = s
+        .split_once(' ')
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    let (day_month, yy) = date
+        .rsplit_once('-')
+        .ok_or_else(|| "custom parsing failed".to_string())?;
+    if yy.len() != 2 {
+        return Err("custom parsing failed".to_string());
+    }
+    // RR: the year closest to the current one, switching century at 50
+    let yy = yy.parse::<i32>().map_err(|e| e.to_string())?;
+    let this_year = Utc::now().year();
+    let century = this_year - this_year % 100;
+    let year = match (yy < 50, this_year % 100 < 50) {
+        (true, false) => century + 100 + yy,
+        (false, true) => century - 100 + yy,
+        _ => century + yy,
+    };
+    let s = format!("{}-{} {}", day_month, year, rest);
+    DateTime::parse_from_str(&s, "%d-%b-%Y %I.%M.%S%.f %p %:z")
+        .map_err(|e| e.to_string())
+        .or_else(|_| {
+            NaiveDateTime::parse_from_str(&s, "%d-%b-%Y %I.%M.%S%.f %p")
+                .map_err(|e| e.to_string())
+                .and_then(with_local_tz)
+        })
+}
+
+/// The value of an ANSI typed literal
+/// eg: TIMESTAMP '2023-08-07 08:23:50', DATE '2023-08-07'
+fn strip_typed_literal(s: &str) -> Option<&str> {
+    let (keyword, value) = s.split_once('\'')?;
+    let keyword = keyword.trim().to_ascii_uppercase();
+    if ["TIMESTAMP", "TIMESTAMP WITH TIME ZONE", "DATE", "TIME"].contains(&keyword.as_str()) {
+        value.strip_suffix('\'')
+    } else {
+        None
+    }
+}
@@ -0,0 +1,93 @@
+//! Log-line timestamps from syslog and friends, which mostly leave out the year.
+//! RFC 5424 timestamps are plain RFC 3339 and are handled by the main chain.
+
+use crate::{tz_offset, with_local_tz, Error};
+use chrono::{
+    DateTime, Datelike, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime,
+};
+
+/// Try to parse the following types of log timestamps
+/// Aug  7 08:23:50 (RFC 3164, space-padded day)
+/// Aug  7 08:23:50.123
+/// 08-07 08:23:50.123 (Android logcat)
+/// *Aug  7 08:23:50.123 UTC: (Cisco, `*` or `.` marks an unsynchronised clock)
+/// *Aug  7 2023 08:23:50.123 UTC:
+/// Aug 7 08:23, Aug 7, 08:23 (any month, day and time without a year)
+pub(crate) fn from_syslog(s: &str) -> Result<DateTime<FixedOffset>, Error> {
+    let s = s
+        .trim()
+        .trim_start_matches(['*', '.'])
+        .trim_end_matches(':');
+    let tokens = s.split_whitespace().collect::<Vec<_>>();
+    match tokens[..] {
+        [date, time] if date.len() == 5 && date.as_bytes()[2] == b'-' => {
+            // trying 08-07 08:23:50.123
+            let (month, day) = date.split_once('-').unwrap_or_default();
+            let month = month.parse::<u32>().map_err(|e| e.to_string())?;
+            let day = day.parse::<u32>().map_err(|e| e.to_string())?;
+            let dt = resolve_year(month, day, parse_time(time)?)?;
+            with_local_tz(dt)
+        }
+        [month, day, ref rest @ ..] if month.chars().all(char::is_alphabetic) => {
+            // trying Aug  7 [2023] 08:23[:50.123] [UTC]
+            let month = NaiveDate::parse_from_str(&format!("{} 1 2000", month), "%b %d %Y")
+                .map_err(|e| e.to_string())?
+                .month();
+            let day = day
+                .trim_end_matches(',')
+                .parse::<u32>()
+                .map_err(|e| e.to_string())?;
+            let (year, rest) = match rest {
+                [year, ref rest @ ..] if year.len() == 4 && year.parse::<i32>().is_ok() => {
+                    (Some(*year), rest)
+                }
+                _ => (None, rest),
+            };
+            let (time, tz) = match rest {
+                [time] => (parse_clock(time)?, None),
+                [time, tz] => (parse_clock(time)?, Some(*tz)),
+                _ => return Err("custom parsing failed".to_string()),
+            };
+            let dt = match year {
+                Some(year) => year
+                    .parse::<i32>()
+                    .ok()
+                    .and_then(|year| NaiveDate::from_ymd_opt(year, month, day))
+                    .map(|x| x.and_time(time))
+                    .ok_or_else(|| "custom parsing failed".to_string())?,
+                None => resolve_year(month, day, time)?,
+            };
+            match tz {
+                Some(tz) => tz_offset(tz)
+                    .and_then(|offset| dt.and_local_timezone(offset).single())
+                    .ok_or_else(|| "custom parsing failed".to_string()),
+                None => with_local_tz(dt),
+            }
+        }
+        _ => Err("custom parsing failed".to_string()),
+    }
+}
+
+/// Log timestamps always carry seconds, optionally with a fraction
+fn parse_time(s: &str) -> Result<NaiveTime, Error> {
+    NaiveTime::parse_from_str(s, "%H:%M:%S%.f").map_err(|e| e.to_string())
+}
+
+/// A log time, or hours and minutes as in other dates without a year
+fn parse_clock(s: &str) -> Result<NaiveTime, Error> {
+    parse_time(s).or_else(|_| NaiveTime::parse_from_str(s, "%H:%M").map_err(|e| e.to_string()))
+}
+
+/// Log lines and other dates with a time but no year are written in the past,
+/// so use the current year unless that puts the timestamp more than a day
+/// ahead of now; in that case the line is from the year before (eg: a `Dec 31`
+/// line read in January). Feb 29 goes back to the last leap year.
+fn resolve_year(month: u32, day: u32, time: NaiveTime) -> Result<NaiveDateTime, Error> {
+    let now = Local::now().naive_local();
+    (0..8)
+        .map(|n| now.year() - n)
+        .filter_map(|year| NaiveDate::from_ymd_opt(year, month, day))
+        .map(|x| x.and_time(time))
+        .find(|x| *x <= now + Duration::days(1))
+        .ok_or_else(|| "custom parsing failed".to_string())
+}
@@ -84,3 +84,994 @@ fn test_m_d_yyyy_h_m_s_tt() {
         .to_rfc3339()
         .starts_with("2023-08-07T08:23:50+02:00"));
 }
+
+#[test]
+fn test_http_rfc850_date() {
+    let date = "Sunday, 06-Nov-94 08:49:37 GMT";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "1994-11-06T08:49:37+00:00");
+}
+
+#[test]
+fn test_http_asctime_date() {
+    use crate::parse_http_date;
+
+    let test = parse_http_date("Sun Nov  6 08:49:37 1994");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "1994-11-06T08:49:37+00:00");
+}
+
+#[test]
+fn test_asctime_is_local_time() {
+    std::env::set_var("TZ", "Europe/Berlin");
+    let date = "Mon Aug 7 08:23:50 2023";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+}
+
+#[test]
+fn test_http_expires() {
+    use crate::parse_http_expires;
+
+    let expires = parse_http_expires("Wed, 07-Aug-2023 08:23:50 GMT");
+    assert_eq!(expires.0.to_rfc3339(), "2023-08-07T08:23:50+00:00");
+    assert_eq!(parse_http_expires("0").0.timestamp(), 0);
+    assert_eq!(
+        parse_http_expires("Monday, 06-Nov-94 08:49:37 GMT")
+            .0
+            .timestamp(),
+        0
+    );
+}
+
+#[test]
+fn test_syslog_padded_day() {
+    let date = "Aug  7 08:23:50.123";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    let parsed = test.unwrap().0;
+    assert!(parsed.to_rfc3339().contains("-08-07T08:23:50.123"));
+    assert!(parsed <= chrono::Local::now() + chrono::Duration::days(1));
+}
+
+#[test]
+fn test_yearless_same_year() {
+    let soon = chrono::Local::now() + chrono::Duration::days(7);
+    let mut parsed = Vec::new();
+    for format in ["%b %e %H:%M:%S", "%b %-d %H:%M:%S", "%b %-d %H:%M"] {
+        let date = soon.format(format).to_string();
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        let test = test.unwrap().0;
+        assert!(test < chrono::Local::now(), "{}", date);
+        parsed.push(test.date_naive());
+    }
+    assert!(parsed.iter().all(|x| *x == parsed[0]));
+}
+
+#[test]
+fn test_logcat() {
+    let date = "08-07 08:23:50.123";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test.unwrap().0.to_rfc3339().contains("-08-07T08:23:50.123"));
+}
+
+#[test]
+fn test_cisco_log() {
+    let date = "*Aug  7 2023 08:23:50.123 UTC:";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-08-07T08:23:50.123+00:00"
+    );
+}
+
+#[test]
+fn test_common_log_format() {
+    let date = "[07/Aug/2023:08:23:50 +0200]";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+}
+
+#[test]
+fn test_nginx_time_iso8601() {
+    let date = "[2023-08-07T08:23:50+02:00]";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+}
+
+#[test]
+fn test_git_raw_date() {
+    let date = "1672903639 +0200";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-01-05T09:27:19+02:00");
+}
+
+#[test]
+fn test_git_default_date() {
+    let date = "Mon Aug 7 08:23:50 2023 +0200";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+}
+
+#[test]
+fn test_git_local_date() {
+    std::env::set_var("TZ", "Europe/Berlin");
+    let date = "Sun Oct 18 10:00:00 2026";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2026-10-18T10:00:00+02:00");
+}
+
+#[test]
+fn test_git_human_date() {
+    use chrono::{Datelike, NaiveDate, Weekday};
+
+    let test = "Sat 08:23 +0200".parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    let parsed = test.unwrap().0;
+    assert_eq!(parsed.weekday(), Weekday::Sat);
+    assert!(parsed.to_rfc3339().ends_with("T08:23:00+02:00"));
+
+    let year = chrono::Local::now().year();
+    let date = NaiveDate::from_ymd_opt(year, 1, 7).unwrap();
+    let test = date
+        .format("%a %b %-d 08:23")
+        .to_string()
+        .parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with(&format!("{}-01-07T08:23:00", year)));
+}
+
+#[test]
+fn test_git_relative_date() {
+    let date = "2 years, 5 months ago";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    let ago = chrono::Local::now().fixed_offset() - test.unwrap().0;
+    assert_eq!(ago.num_days(), 2 * 365 + 5 * 30);
+}
+
+#[test]
+fn test_exif_date() {
+    let date = "2023:08:07 08:23:50";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-08-07T08:23:50"));
+}
+
+#[test]
+fn test_exif_with_offset() {
+    use crate::parse_exif;
+
+    let test = parse_exif("2023:08:07 08:23:50", "+02:00");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+    assert!(parse_exif("2023:08:07 08:23:50", "+25:00").is_err());
+}
+
+#[test]
+fn test_xmp_partial_date() {
+    let date = "2023-08-07T08:23Z";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:00+00:00");
+
+    let test = "2023-08".parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-08-01T00:00:00"));
+    for date in ["8-7", "1-2", "99-12"] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_err(), "{}", date);
+    }
+}
+
+#[test]
+fn test_xmp_year() {
+    use crate::parse_xmp;
+
+    let test = parse_xmp("2023");
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-01-01T00:00:00"));
+    assert!(parse_xmp("2023-08-07T08:23+02:00").is_ok());
+    assert!(parse_xmp("2023-08-07").is_ok());
+}
+
+#[test]
+fn test_asn1_utctime() {
+    let test = "230807082350Z".parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+00:00");
+
+    let test = "500101000000Z".parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "1950-01-01T00:00:00+00:00");
+}
+
+#[test]
+fn test_asn1_generalizedtime() {
+    let test = "20230807082350.123Z".parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-08-07T08:23:50.123+00:00"
+    );
+
+    let test = "20230807082350+0200".parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+
+    let test = "20230807082350".parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-08-07T08:23:50"));
+}
+
+#[test]
+fn test_openssl_dates() {
+    let date = "notAfter=Aug  7 08:23:50 2023 GMT";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+00:00");
+}
+
+#[test]
+fn test_pdf_date() {
+    let date = "D:20230807082350+02'00'";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+}
+
+#[test]
+fn test_pdf_partial_date() {
+    let date = "D:202308";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-08-01T00:00:00"));
+}
+
+#[test]
+fn test_ical_tzid() {
+    use crate::{parse_ical, ICalDateTime};
+
+    let test = parse_ical("DTSTART;TZID=Europe/Berlin:20230807T082350");
+    assert!(test.is_ok());
+    match test.unwrap() {
+        ICalDateTime::Zoned(parsed) => {
+            assert_eq!(parsed.0.to_rfc3339(), "2023-08-07T08:23:50+02:00")
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+    assert!(parse_ical("DTSTART;TZID=Nowhere/Special:20230807T082350").is_err());
+}
+
+#[test]
+fn test_ical_date_and_floating() {
+    use crate::{parse_ical, ICalDateTime};
+    use chrono::NaiveDate;
+
+    let date = NaiveDate::from_ymd_opt(2023, 8, 7).unwrap();
+    assert_eq!(
+        parse_ical("DTSTART;VALUE=DATE:20230807"),
+        Ok(ICalDateTime::Date(date))
+    );
+    assert_eq!(
+        parse_ical("DTSTART:20230807T082350"),
+        Ok(ICalDateTime::Floating(date.and_hms_opt(8, 23, 50).unwrap()))
+    );
+}
+
+#[test]
+fn test_ical_utc_value() {
+    let date = "20230807T082350Z";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+00:00");
+}
+
+#[test]
+fn test_js_to_string() {
+    let date = "Mon Aug 07 2023 08:23:50 GMT+0200 (Central European Summer Time)";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+}
+
+#[test]
+fn test_js_to_string_zone_mismatch() {
+    let date = "Mon Aug 07 2023 08:23:50 GMT+0100 (Central European Summer Time)";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_err());
+}
+
+#[test]
+fn test_js_to_locale_date_string() {
+    let date = "8/7/2023";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-08-07T00:00:00"));
+}
+
+#[test]
+fn test_js_locale_fallback_short_year() {
+    let date = "12/21/00";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2000-12-21T00:00:00"));
+}
+
+#[test]
+fn test_email_comment_and_obsolete_year() {
+    let date = "Sat, 7 Aug 99 08:23:50 (Pacific) PDT";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "1999-08-07T08:23:50-07:00");
+}
+
+#[test]
+fn test_email_military_zone() {
+    use crate::parse_email_date;
+
+    let date = "Mon, 7 Aug 2023 08:23:50 A";
+    let test = parse_email_date(date);
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+00:00");
+}
+
+#[test]
+fn test_email_unknown_zone() {
+    let date = "07 Aug 2023 08:23:50 XYZ";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_err());
+}
+
+#[test]
+fn test_email_received_header() {
+    use crate::parse_email_date;
+
+    let header = "Received: from mx.example.com (mx.example.com [192.0.2.1])\r\n\tby mail.example.org with ESMTPS; Mon, 7 Aug 2023 08:23:50 +0200 (CEST)";
+    let test = parse_email_date(header);
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+}
+
+#[test]
+fn test_postgres_timestamptz() {
+    let date = "2023-08-07 08:23:50.123456+02";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-08-07T08:23:50.123456+02:00"
+    );
+}
+
+#[test]
+fn test_oracle_timestamp() {
+    let date = "07-AUG-23 08.23.50.000000 PM +02:00";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T20:23:50+02:00");
+}
+
+#[test]
+fn test_sql_sentinels() {
+    use crate::{parse_sql_timestamp, SqlTimestamp};
+
+    assert_eq!(parse_sql_timestamp("infinity"), Ok(SqlTimestamp::Infinity));
+    assert_eq!(
+        parse_sql_timestamp("-infinity"),
+        Ok(SqlTimestamp::NegativeInfinity)
+    );
+    assert_eq!(
+        parse_sql_timestamp("0000-00-00 00:00:00"),
+        Ok(SqlTimestamp::ZeroDate)
+    );
+    assert_eq!(
+        parse_sql_timestamp("0000-00-00 00:00:00.000000"),
+        Ok(SqlTimestamp::ZeroDate)
+    );
+    assert!(parse_sql_timestamp("0000-00-00 00:00:00.000001").is_err());
+    match parse_sql_timestamp("TIMESTAMP '2023-08-07 08:23:50.1234567 +02:00'") {
+        Ok(SqlTimestamp::Value(parsed)) => {
+            assert_eq!(parsed.0.to_rfc3339(), "2023-08-07T08:23:50.123456700+02:00")
+        }
+        other => panic!("unexpected {:?}", other),
+    }
+}
+
+#[test]
+fn test_extended_zone_suffix() {
+    use crate::parse_extended;
+
+    let test = parse_extended("2023-08-07T08:23:50+02:00[Europe/Berlin][u-ca=gregory]");
+    assert!(test.is_ok());
+    let parsed = test.unwrap();
+    assert_eq!(parsed.datetime.0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+    assert_eq!(parsed.time_zone.as_deref(), Some("Europe/Berlin"));
+    assert_eq!(parsed.calendar.as_deref(), Some("gregory"));
+}
+
+#[test]
+fn test_extended_critical_flag() {
+    use crate::parse_extended;
+
+    let test = parse_extended("2023-08-07T08:23:50+01:00[Europe/Berlin]");
+    assert!(test.is_ok());
+    assert!(test.unwrap().inconsistent);
+    assert!(
+        !parse_extended("2023-08-07T06:23:50Z[Europe/Berlin]")
+            .unwrap()
+            .inconsistent
+    );
+    assert!(parse_extended("2023-08-07T08:23:50+01:00[!Europe/Berlin]").is_err());
+    assert!(parse_extended("2023-08-07T08:23:50+02:00[x-foo=bar]").is_ok());
+    assert!(parse_extended("2023-08-07T08:23:50+02:00[!x-foo=bar]").is_err());
+}
+
+#[test]
+fn test_python_datetime_repr() {
+    let date = "datetime.datetime(2023, 8, 7, 8, 23, 50, tzinfo=datetime.timezone.utc)";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+00:00");
+}
+
+#[test]
+fn test_go_time_string() {
+    let date = "2023-08-07 08:23:50.123456789 +0200 CEST m=+0.000012";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-08-07T08:23:50.123456789+02:00"
+    );
+}
+
+#[test]
+fn test_go_time_string_trailing_words() {
+    let date = "2023-08-07 08:23:50 +0200 trailing garbage words";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_err());
+    let date = "2023-08-07 08:23:50 -0300 -03";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50-03:00");
+}
+
+#[test]
+fn test_java_date_to_string() {
+    let date = "Mon Aug 07 08:23:50 CEST 2023";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50+02:00");
+}
+
+#[test]
+fn test_ms_json_date() {
+    let date = "/Date(1672903639123)/";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().0.to_rfc3339(),
+        "2023-01-05T07:27:19.123+00:00"
+    );
+}
+
+#[test]
+fn test_ms_json_date_with_offset() {
+    let date = "\\/Date(1672903639123+0200)\\/";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    let parsed = test.unwrap().0;
+    assert_eq!(parsed.to_rfc3339(), "2023-01-05T09:27:19.123+02:00");
+    assert_eq!(parsed.timestamp_millis(), 1672903639123);
+}
+
+#[test]
+fn test_ms_json_date_negative() {
+    let date = "/Date(-86400000)/";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "1969-12-31T00:00:00+00:00");
+}
+
+#[test]
+fn test_ordinal_day() {
+    for date in [
+        "7th August 2023",
+        "August 7th, 2023",
+        "the 7th of August 2023",
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert!(test
+            .unwrap()
+            .0
+            .to_rfc3339()
+            .starts_with("2023-08-07T00:00:00"));
+    }
+}
+
+#[test]
+fn test_ordinal_day_with_time() {
+    let date = "3rd Jan 2024 at 5pm";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2024-01-03T17:00:00"));
+}
+
+#[test]
+fn test_ordinal_month_first_with_time() {
+    let date = "August 7th, 2023 at 5:30pm";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-08-07T17:30:00"));
+}
+
+#[test]
+fn test_ordinal_weekday_without_year() {
+    use chrono::{Datelike, Weekday};
+
+    let date = "Monday, 2nd October";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    let parsed = test.unwrap().0;
+    assert_eq!(parsed.weekday(), Weekday::Mon);
+    assert_eq!((parsed.month(), parsed.day()), (10, 2));
+}
+
+#[test]
+fn test_ordinal_suffix_mismatch() {
+    assert!("2th May 2023".parse::<DateTimeFixedOffset>().is_err());
+    assert!("12st May 2023".parse::<DateTimeFixedOffset>().is_err());
+    assert!("Tuesday 7th August 2023"
+        .parse::<DateTimeFixedOffset>()
+        .is_err());
+    assert!("7th".parse::<DateTimeFixedOffset>().is_err());
+    assert!("the 22nd".parse::<DateTimeFixedOffset>().is_err());
+}
+
+#[test]
+fn test_localized_month_names() {
+    for date in [
+        "7 août 2023",
+        "le 7 août 2023",
+        "Montag, 7. August 2023",
+        "7 de agosto de 2023",
+        "7 agosto 2023",
+        "7 augustus 2023",
+        "7 sierpnia 2023 r.",
+        "7 августа 2023 г.",
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap()
+                .0
+                .to_rfc3339()
+                .starts_with("2023-08-07T00:00:00"),
+            "{}",
+            date
+        );
+    }
+}
+
+#[test]
+fn test_localized_time() {
+    let date = "le 7 août 2023 à 8h23";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-08-07T08:23:00"));
+}
+
+#[test]
+fn test_localized_with_locale() {
+    use crate::{parse_localized, Locale};
+
+    let test = parse_localized("7 ago 2023", Locale::Spanish);
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-08-07T00:00:00"));
+    assert!(parse_localized("7 ago 2023", Locale::Auto).is_err());
+    assert!(parse_localized("Dienstag, 7. August 2023", Locale::German).is_err());
+}
+
+#[test]
+fn test_cjk_dates() {
+    for (date, expected) in [
+        ("2023年8月7日 8時23分50秒", "2023-08-07T08:23:50"),
+        ("2023年8月7日(月) 午後3時", "2023-08-07T15:00:00"),
+        ("2023년 8월 7일 오후 3시 23분", "2023-08-07T15:23:00"),
+        ("2023年8月7日 下午3:23", "2023-08-07T15:23:00"),
+        ("２０２３年８月７日 午前１２時５分", "2023-08-07T00:05:00"),
+        ("2023年8月7日 星期一", "2023-08-07T00:00:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap().0.to_rfc3339().starts_with(expected),
+            "{}",
+            date
+        );
+    }
+}
+
+#[test]
+fn test_cjk_invalid() {
+    assert!("2023年8月7日(火)".parse::<DateTimeFixedOffset>().is_err());
+    assert!("2023年8月7日 午後15時"
+        .parse::<DateTimeFixedOffset>()
+        .is_err());
+    assert!("2023年2月30日".parse::<DateTimeFixedOffset>().is_err());
+}
+
+#[test]
+fn test_japanese_era() {
+    for (date, expected) in [
+        ("令和5年8月7日", "2023-08-07T00:00:00"),
+        ("平成31年4月30日", "2019-04-30T00:00:00"),
+        ("令和元年5月1日", "2019-05-01T00:00:00"),
+        ("R5.8.7", "2023-08-07T00:00:00"),
+        ("H31/4/30", "2019-04-30T00:00:00"),
+        ("S64.1.7", "1989-01-07T00:00:00"),
+        ("明治45年7月29日", "1912-07-29T00:00:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap().0.to_rfc3339().starts_with(expected),
+            "{}",
+            date
+        );
+    }
+}
+
+#[test]
+fn test_japanese_era_boundaries() {
+    assert!("平成31年5月1日".parse::<DateTimeFixedOffset>().is_err());
+    assert!("S64.1.8".parse::<DateTimeFixedOffset>().is_err());
+    assert!("R0.1.1".parse::<DateTimeFixedOffset>().is_err());
+}
+
+#[test]
+fn test_thai_and_minguo_years() {
+    for date in [
+        "7 ส.ค. 2566",
+        "7 สิงหาคม พ.ศ. 2566",
+        "วันจันทร์ที่ 7 สิงหาคม 2566",
+        "๗ ส.ค. ๒๕๖๖",
+        "民國112年8月7日",
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap()
+                .0
+                .to_rfc3339()
+                .starts_with("2023-08-07T00:00:00"),
+            "{}",
+            date
+        );
+    }
+    assert!("วันอังคารที่ 7 สิงหาคม 2566"
+        .parse::<DateTimeFixedOffset>()
+        .is_err());
+}
+
+#[test]
+fn test_parse_with_era() {
+    use crate::{parse_with_era, Era};
+
+    for (date, era) in [
+        ("07/08/2566", Era::Buddhist),
+        ("2566-08-07", Era::Buddhist),
+        ("112/08/07", Era::Minguo),
+        ("民國112年8月7日", Era::Minguo),
+    ] {
+        let test = parse_with_era(date, era);
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap()
+                .0
+                .to_rfc3339()
+                .starts_with("2023-08-07T00:00:00"),
+            "{}",
+            date
+        );
+    }
+    assert!(parse_with_era("0/08/07", Era::Minguo).is_err());
+}
+
+#[test]
+fn test_hijri_tabular() {
+    for (date, expected) in [
+        ("15 Muharram 1445", "2023-08-02T00:00:00"),
+        ("1445-01-15 AH", "2023-08-02T00:00:00"),
+        ("١٥ محرم ١٤٤٥ هـ", "2023-08-02T00:00:00"),
+        ("1 Ramadan 1444", "2023-03-23T00:00:00"),
+        ("15 Rabi' al-Awwal 1445", "2023-09-30T00:00:00"),
+        ("30 Dhu al-Hijjah 1445", "2024-07-07T00:00:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap().0.to_rfc3339().starts_with(expected),
+            "{}",
+            date
+        );
+    }
+    // 1444 is not a leap year
+    assert!("30 Dhu al-Hijjah 1444"
+        .parse::<DateTimeFixedOffset>()
+        .is_err());
+}
+
+#[test]
+fn test_hijri_umm_al_qura() {
+    use crate::{parse_hijri, HijriCalendar, UmmAlQuraTable};
+    use chrono::NaiveDate;
+
+    let start = NaiveDate::from_ymd_opt(2023, 7, 19).unwrap();
+    let table = UmmAlQuraTable::new(1445, start, vec![29, 30, 29]).unwrap();
+    let calendar = HijriCalendar::UmmAlQura(&table);
+    let test = parse_hijri("1 Safar 1445", calendar);
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-08-17T00:00:00"));
+    assert!(parse_hijri("30 Muharram 1445", calendar).is_err());
+    assert!(parse_hijri("1 Jumada al-Awwal 1445", calendar).is_err());
+    assert!(UmmAlQuraTable::new(1445, start, vec![28]).is_err());
+}
+
+#[test]
+fn test_jalali_month_names() {
+    for (date, expected) in [
+        ("۱۶ مرداد ۱۴۰۲", "2023-08-07T00:00:00"),
+        ("١٦ مرداد ١٤٠٢", "2023-08-07T00:00:00"),
+        ("16 Mordad 1402", "2023-08-07T00:00:00"),
+        ("1 Farvardin 1403", "2024-03-20T00:00:00"),
+        ("30 Esfand 1399", "2021-03-20T00:00:00"),
+        ("11 دی 1357", "1979-01-01T00:00:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap().0.to_rfc3339().starts_with(expected),
+            "{}",
+            date
+        );
+    }
+}
+
+#[test]
+fn test_parse_jalali() {
+    use crate::parse_jalali;
+
+    let test = parse_jalali("1402/05/16");
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-08-07T00:00:00"));
+    let test = parse_jalali("۱۴۰۳-۱۲-۳۰");
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2025-03-20T00:00:00"));
+    // 1402 is not a leap year
+    assert!(parse_jalali("1402/12/30").is_err());
+}
+
+#[test]
+fn test_hebrew_dates() {
+    for (date, expected) in [
+        ("14 Av 5783", "2023-08-01T00:00:00"),
+        ("י״ד באב תשפ״ג", "2023-08-01T00:00:00"),
+        ("1 Tishrei 5784", "2023-09-16T00:00:00"),
+        ("14 Adar 5783", "2023-03-07T00:00:00"),
+        ("14 Adar I 5784", "2024-02-23T00:00:00"),
+        ("14 Adar II 5784", "2024-03-24T00:00:00"),
+        ("14 באדר ב׳ ה׳תשפ״ד", "2024-03-24T00:00:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap().0.to_rfc3339().starts_with(expected),
+            "{}",
+            date
+        );
+    }
+}
+
+#[test]
+fn test_hebrew_invalid() {
+    // 5783 has a single Adar, and Kislev 5784 only 29 days
+    assert!("14 Adar II 5783".parse::<DateTimeFixedOffset>().is_err());
+    assert!("30 Kislev 5784".parse::<DateTimeFixedOffset>().is_err());
+}
+
+#[test]
+fn test_old_style_dates() {
+    for (date, expected) in [
+        ("10 Feb 1750/51", "1751-02-21T00:00:00"),
+        ("Feb 10, 1750/51", "1751-02-21T00:00:00"),
+        ("1 Jan 1699/00", "1700-01-11T00:00:00"),
+        ("1 March 1712 O.S.", "1712-03-12T00:00:00"),
+        ("29 Feb 1700 O.S.", "1700-03-11T00:00:00"),
+        ("12 March 1712 (N.S.)", "1712-03-12T00:00:00"),
+        ("Thursday 4 October 1582 O.S.", "1582-10-14T00:00:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap().0.to_rfc3339().starts_with(expected),
+            "{}",
+            date
+        );
+    }
+    assert!("Friday 4 October 1582 O.S."
+        .parse::<DateTimeFixedOffset>()
+        .is_err());
+}
+
+#[test]
+fn test_parse_julian() {
+    use crate::parse_julian;
+
+    for (date, expected) in [
+        ("18 January 1700", "1700-01-28T00:00:00"),
+        ("1712-03-01", "1712-03-12T00:00:00"),
+        ("12 March 1712 N.S.", "1712-03-12T00:00:00"),
+    ] {
+        let test = parse_julian(date);
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap().0.to_rfc3339().starts_with(expected),
+            "{}",
+            date
+        );
+    }
+}
+
+#[test]
+fn test_roman_numeral_months() {
+    for (date, expected) in [
+        ("7.VIII.2023", "2023-08-07T00:00:00"),
+        ("7 VIII 2023", "2023-08-07T00:00:00"),
+        ("2023-VIII-07", "2023-08-07T00:00:00"),
+        ("VIII 7 2023", "2023-08-07T00:00:00"),
+        ("7/viii/2023", "2023-08-07T00:00:00"),
+        ("7. VIII. 2023 08:23", "2023-08-07T08:23:00"),
+        ("7.x.2023", "2023-10-07T00:00:00"),
+        ("X 1 2000", "2000-10-01T00:00:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap().0.to_rfc3339().starts_with(expected),
+            "{}",
+            date
+        );
+    }
+    assert!("31.IV.2023".parse::<DateTimeFixedOffset>().is_err());
+    assert!("7.XIII.2023".parse::<DateTimeFixedOffset>().is_err());
+    assert!("x 1 2000".parse::<DateTimeFixedOffset>().is_err());
+    assert!("i 7 2023".parse::<DateTimeFixedOffset>().is_err());
+}
+
+#[test]
+fn test_unicode_digits_and_punctuation() {
+    for (date, expected) in [
+        ("２０２３-０８-０７", "2023-08-07T00:00:00"),
+        ("٢٠٢٣-٠٨-٠٧", "2023-08-07T00:00:00"),
+        ("۲۰۲۳-۰۸-۰۷", "2023-08-07T00:00:00"),
+        ("२०२३-०८-०७", "2023-08-07T00:00:00"),
+        ("2023\u{2013}08\u{2014}07", "2023-08-07T00:00:00"),
+        ("7\u{a0}August\u{a0}2023", "2023-08-07T00:00:00"),
+        (
+            "08\u{2044}07\u{2044}2023, 8:23:50\u{202f}PM",
+            "2023-08-07T20:23:50",
+        ),
+        ("\u{200f}2023-08-07", "2023-08-07T00:00:00"),
+    ] {
+        let test = date.parse::<DateTimeFixedOffset>();
+        assert!(test.is_ok(), "{}", date);
+        assert!(
+            test.unwrap().0.to_rfc3339().starts_with(expected),
+            "{}",
+            date
+        );
+    }
+}
+
+#[test]
+fn test_unicode_in_public_parsers() {
+    use crate::{parse_email_date, parse_extended, parse_with_era, Era};
+
+    let test = parse_extended("2023-08-07T08:23:50\u{2212}04:00[America/New_York]");
+    assert!(test.is_ok());
+    assert_eq!(
+        test.unwrap().datetime.0.to_rfc3339(),
+        "2023-08-07T08:23:50-04:00"
+    );
+    let test = parse_email_date("Mon, 7 Aug 2023 08:23:50 \u{2212}0500");
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50-05:00");
+    let test = parse_with_era("๗/๘/๒๕๖๖", Era::Buddhist);
+    assert!(test.is_ok());
+    assert!(test
+        .unwrap()
+        .0
+        .to_rfc3339()
+        .starts_with("2023-08-07T00:00:00"));
+}
+
+#[test]
+fn test_unicode_minus_offset() {
+    let date = "2023-08-07T08:23:50\u{2212}05:00";
+    let test = date.parse::<DateTimeFixedOffset>();
+    assert!(test.is_ok());
+    assert_eq!(test.unwrap().0.to_rfc3339(), "2023-08-07T08:23:50-05:00");
+}